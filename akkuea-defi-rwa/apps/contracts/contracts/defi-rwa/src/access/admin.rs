@@ -1,21 +1,38 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env};
 
 use crate::access::roles::{Role, RoleKey, RoleStorage};
-
-#[derive(Debug)]
-pub enum AdminError {
-    NotAdmin,
-    NotPendingAdmin,
-    AlreadyInitialized,
-    ZeroAddress,
-}
+use crate::errors::ContractError;
+use crate::events::AccessEvents;
+
+/// Pause flag for pool deposits
+pub const PAUSE_DEPOSIT: u32 = 1 << 0;
+/// Pause flag for pool withdrawals, redemptions and collateral removal
+pub const PAUSE_WITHDRAW: u32 = 1 << 1;
+/// Pause flag for borrows and flash loans
+pub const PAUSE_BORROW: u32 = 1 << 2;
+/// Pause flag for repayments
+pub const PAUSE_REPAY: u32 = 1 << 3;
+/// Pause flag for liquidations
+pub const PAUSE_LIQUIDATE: u32 = 1 << 4;
+/// Pause flag for share, receipt and order book transfers
+pub const PAUSE_SHARE_TRANSFER: u32 = 1 << 5;
+/// Pause flag for dividend claims
+pub const PAUSE_DIVIDEND_CLAIM: u32 = 1 << 6;
+/// Every pause flag
+pub const PAUSE_ALL: u32 = PAUSE_DEPOSIT
+    | PAUSE_WITHDRAW
+    | PAUSE_BORROW
+    | PAUSE_REPAY
+    | PAUSE_LIQUIDATE
+    | PAUSE_SHARE_TRANSFER
+    | PAUSE_DIVIDEND_CLAIM;
 
 pub struct AdminControl;
 
 impl AdminControl {
     pub fn initialize(env: &Env, admin: &Address) {
         if Self::is_initialized(env) {
-            panic!("Admin already initialized")
+            panic_with_error!(env, ContractError::AlreadyInitialized)
         }
 
         env.storage().instance().set(&RoleKey::Admin, admin);
@@ -39,7 +56,7 @@ impl AdminControl {
 
     pub fn require_admin(env: &Env, caller: &Address) {
         if !Self::is_admin(env, caller) {
-            panic!("Caller not admin")
+            panic_with_error!(env, ContractError::NotAdmin)
         }
     }
 
@@ -54,18 +71,16 @@ impl AdminControl {
         let pending: Option<Address> = env.storage().instance().get(&RoleKey::PendingAdmin);
 
         match pending {
-            Some(pending_admin) => {
-                if pending_admin == new_admin.clone() {
-                    if let Some(old_admin) = Self::get_admin(env) {
-                        RoleStorage::revoke_role(env, &old_admin, &Role::Admin);
-                    }
-
-                    env.storage().instance().set(&RoleKey::Admin, new_admin);
-                    RoleStorage::grant_role(env, new_admin, &Role::Admin);
-                    env.storage().instance().remove(&RoleKey::PendingAdmin);
+            Some(pending_admin) if pending_admin == new_admin.clone() => {
+                if let Some(old_admin) = Self::get_admin(env) {
+                    RoleStorage::revoke_role(env, &old_admin, &Role::Admin);
                 }
+
+                env.storage().instance().set(&RoleKey::Admin, new_admin);
+                RoleStorage::grant_role(env, new_admin, &Role::Admin);
+                env.storage().instance().remove(&RoleKey::PendingAdmin);
             }
-            _ => panic!("Caller is not pending admin"),
+            _ => panic_with_error!(env, ContractError::NotPendingAdmin),
         }
     }
 
@@ -90,6 +105,13 @@ impl PauseControl {
     }
 
     pub fn can_pause(env: &Env, address: &Address) -> bool {
+        let is_guardian = RoleStorage::has_role(env, address, &Role::Guardian);
+
+        Self::can_unpause(env, address) || is_guardian
+    }
+
+    // Guardians can stop the contract but not resume it
+    pub fn can_unpause(env: &Env, address: &Address) -> bool {
         let is_admin = AdminControl::is_admin(env, address);
         let is_pauser = RoleStorage::has_role(env, address, &Role::Pauser);
 
@@ -99,7 +121,7 @@ impl PauseControl {
     pub fn require_can_pause(env: &Env, address: &Address) {
         let can_pause = Self::can_pause(env, address);
         if !can_pause {
-            panic!("Caller does not have permission to pause")
+            panic_with_error!(env, ContractError::Unauthorized)
         }
     }
 
@@ -109,19 +131,59 @@ impl PauseControl {
     }
 
     pub fn unpause(env: &Env, caller: &Address) {
-        Self::require_can_pause(env, caller);
+        if !Self::can_unpause(env, caller) {
+            panic_with_error!(env, ContractError::Unauthorized)
+        }
         env.storage().instance().remove(&RoleKey::Paused);
     }
 
     pub fn require_paused(env: &Env) {
         if !Self::is_paused(env) {
-            panic!("Contract not paused")
+            panic_with_error!(env, ContractError::ContractNotPaused)
         }
     }
 
     pub fn require_not_paused(env: &Env) {
         if Self::is_paused(env) {
-            panic!("Contract paused")
+            panic_with_error!(env, ContractError::ContractPaused)
+        }
+    }
+
+    /// Functions paused contract-wide, as a mask of `PAUSE_*` flags
+    pub fn paused_functions(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&RoleKey::PausedFunctions)
+            .unwrap_or(0)
+    }
+
+    // Anyone who may pause can set flags; clearing one requires unpause rights
+    pub fn require_can_update(env: &Env, caller: &Address, current: u32, paused: u32) {
+        if paused & !PAUSE_ALL != 0 {
+            panic_with_error!(env, ContractError::InvalidParameters)
+        }
+        if current & !paused != 0 {
+            if !Self::can_unpause(env, caller) {
+                panic_with_error!(env, ContractError::Unauthorized)
+            }
+        } else {
+            Self::require_can_pause(env, caller);
+        }
+    }
+
+    pub fn set_paused_functions(env: &Env, caller: &Address, paused: u32) {
+        Self::require_can_update(env, caller, Self::paused_functions(env), paused);
+        env.storage()
+            .instance()
+            .set(&RoleKey::PausedFunctions, &paused);
+        AccessEvents::pause_toggled(env, paused, caller.clone());
+    }
+
+    /// Panics if the contract or the given function is paused contract-wide
+    pub fn require_function_active(env: &Env, flag: u32) {
+        Self::require_not_paused(env);
+        if Self::paused_functions(env) & flag != 0 {
+            panic_with_error!(env, ContractError::FunctionPaused)
         }
     }
 }
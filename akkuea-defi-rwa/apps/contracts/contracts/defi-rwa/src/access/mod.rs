@@ -1,23 +1,28 @@
 pub mod admin;
 pub mod roles;
 
-pub use admin::{AdminControl, PauseControl};
+pub use admin::{
+    AdminControl, PauseControl, PAUSE_ALL, PAUSE_BORROW, PAUSE_DEPOSIT, PAUSE_DIVIDEND_CLAIM,
+    PAUSE_LIQUIDATE, PAUSE_REPAY, PAUSE_SHARE_TRANSFER, PAUSE_WITHDRAW,
+};
 pub use roles::{Role, RoleStorage};
 
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::errors::ContractError;
 
 pub fn require_role(env: &Env, caller: &Address, role: &Role) {
     if !RoleStorage::has_role(env, caller, role) {
-        panic!("Caller does not have required role");
+        panic_with_error!(env, ContractError::Unauthorized);
     }
 }
 
 pub fn required_admin_or_role(env: &Env, caller: &Address, role: &Role) {
-    let is_admin = RoleStorage::has_role(env, caller, role);
+    let is_admin = AdminControl::is_admin(env, caller);
     let has_role = RoleStorage::has_role(env, caller, role);
 
-    if !is_admin || !has_role {
-        panic!("Caller is not authorized")
+    if !is_admin && !has_role {
+        panic_with_error!(env, ContractError::Unauthorized)
     }
 }
 
@@ -13,6 +13,10 @@ pub enum Role {
     Verifier,
     // Right to execute liquidations
     Liquidator,
+    // Right to manage KYC allowlists and transfer restrictions
+    Compliance,
+    // Right to cancel timelocked operations and pause the contract
+    Guardian,
 }
 
 #[derive(Clone)]
@@ -20,6 +24,7 @@ pub enum Role {
 pub enum RoleKey {
     Admin,
     Paused,
+    PausedFunctions,
     HasRole(Address, Role),
     RoleMembers(Role),
     PendingAdmin,
@@ -0,0 +1,210 @@
+use soroban_sdk::{panic_with_error, token, Address, Env};
+
+use super::state::{Buyout, BuyoutStatus, BuyoutStorage, BuyoutTerms};
+use crate::compliance::ComplianceStorage;
+use crate::dividends::DividendControl;
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, GovernanceEvents, PropertyEvents};
+use crate::governance::{GovernanceStorage, ProposalAction, ProposalStatus};
+use crate::offering::OfferingStorage;
+use crate::storage::property::PropertyMetadata;
+use crate::storage::shares;
+use crate::token::ShareToken;
+
+/// Buyout and delisting flow
+///
+/// While a buyout is active, shares can only leave contract escrow (cancelled
+/// orders, released collateral, offering claims) so holders can gather them
+/// for redemption. Escrowed shares can still be released after delisting, and
+/// payouts not redeemed by the deadline stay in escrow for `BUYOUT_CLAIM_PERIOD`
+/// longer. Whatever is left after that is swept back to the bidder, who by
+/// then owns the property outright.
+pub struct BuyoutControl;
+
+impl BuyoutControl {
+    /// Start a buyout by depositing payment for every issued share
+    ///
+    /// Every buyout, including one by the property owner, needs an approved
+    /// `SellProperty` proposal naming the bidder as buyer, and must offer at
+    /// least the approved price. A buyout cannot start while a share offering
+    /// is still open, since its buyers could not receive their shares.
+    pub fn start(
+        env: &Env,
+        bidder: &Address,
+        property_id: u64,
+        terms: BuyoutTerms,
+        proposal_id: u64,
+    ) {
+        ShareToken::load_tradable(env, property_id);
+        if OfferingStorage::get_active(env, property_id).is_some() {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+        if terms.total_payment <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        if terms.deadline <= env.ledger().timestamp() {
+            panic_with_error!(env, ContractError::InvalidDeadline);
+        }
+
+        let mut proposal = GovernanceStorage::get_proposal(env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        let approved = proposal.property_id == property_id
+            && proposal.status == ProposalStatus::Approved
+            && matches!(
+                &proposal.action,
+                ProposalAction::SellProperty(buyer, price)
+                    if buyer == bidder && terms.total_payment >= *price
+            );
+        if !approved {
+            panic_with_error!(env, ContractError::Unauthorized);
+        }
+        proposal.status = ProposalStatus::Executed;
+        GovernanceStorage::set_proposal(env, &proposal);
+        GovernanceEvents::proposal_finalized(env, proposal_id, proposal.status);
+
+        token::Client::new(env, &terms.payment_token).transfer(
+            bidder,
+            env.current_contract_address(),
+            &terms.total_payment,
+        );
+
+        let buyout = Buyout {
+            property_id,
+            bidder: bidder.clone(),
+            terms,
+            total_shares: shares::get_total_shares(env, property_id),
+            shares_redeemed: 0,
+            amount_paid_out: 0,
+            status: BuyoutStatus::Active,
+        };
+        BuyoutStorage::set(env, &buyout);
+
+        PropertyEvents::buyout_started(
+            env,
+            property_id_to_string(env, property_id),
+            bidder.clone(),
+            buyout.terms.total_payment,
+            buyout.terms.deadline,
+        );
+    }
+
+    /// Burn all of a holder's shares for their pro-rata share of the payment
+    ///
+    /// # Returns
+    /// * `i128` - Payout
+    pub fn redeem(env: &Env, holder: &Address, property_id: u64) -> i128 {
+        let mut buyout = BuyoutStorage::get(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if buyout.status == BuyoutStatus::Swept
+            || env.ledger().timestamp() >= buyout.claim_deadline()
+        {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        if ComplianceStorage::is_frozen(env, property_id, holder) {
+            panic_with_error!(env, ContractError::AddressFrozen);
+        }
+        let redeemed = shares::get_balance(env, property_id, holder);
+        if redeemed == 0 {
+            panic_with_error!(env, ContractError::InsufficientShares);
+        }
+
+        DividendControl::settle(env, property_id, holder);
+        shares::decrease_balance(env, property_id, holder, redeemed);
+        let total_shares = shares::get_total_shares(env, property_id);
+        shares::set_total_shares(env, property_id, total_shares - redeemed);
+
+        // The last redemption takes the remainder so no rounding dust is left
+        buyout.shares_redeemed += redeemed;
+        let payout = if buyout.shares_redeemed == buyout.total_shares {
+            buyout.escrow()
+        } else {
+            buyout.payout(redeemed)
+        };
+        buyout.amount_paid_out += payout;
+        BuyoutStorage::set(env, &buyout);
+
+        token::Client::new(env, &buyout.terms.payment_token).transfer(
+            &env.current_contract_address(),
+            holder,
+            &payout,
+        );
+
+        PropertyEvents::shares_redeemed(
+            env,
+            property_id_to_string(env, property_id),
+            holder.clone(),
+            redeemed as i128,
+            payout,
+        );
+
+        payout
+    }
+
+    /// Delist the property once the redemption deadline has passed
+    ///
+    /// # Returns
+    /// * `i128` - Payment left in escrow for holders who have not redeemed
+    pub fn finalize(env: &Env, property_id: u64) -> i128 {
+        let mut buyout = BuyoutStorage::get(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if buyout.status != BuyoutStatus::Active {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        if env.ledger().timestamp() < buyout.terms.deadline {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+
+        buyout.status = BuyoutStatus::Completed;
+        BuyoutStorage::set(env, &buyout);
+        let mut metadata = PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        metadata.set_active(env, false);
+
+        PropertyEvents::property_delisted(
+            env,
+            property_id_to_string(env, property_id),
+            buyout.escrow(),
+        );
+
+        buyout.escrow()
+    }
+
+    /// Return payouts nobody redeemed within the claim period to the bidder
+    ///
+    /// # Returns
+    /// * `i128` - Amount swept
+    pub fn sweep(env: &Env, property_id: u64) -> i128 {
+        let mut buyout = BuyoutStorage::get(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        match buyout.status {
+            BuyoutStatus::Active => panic_with_error!(env, ContractError::TooEarly),
+            BuyoutStatus::Swept => panic_with_error!(env, ContractError::Closed),
+            BuyoutStatus::Completed => {}
+        }
+        if env.ledger().timestamp() < buyout.claim_deadline() {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+
+        let unclaimed = buyout.escrow();
+        buyout.amount_paid_out = buyout.terms.total_payment;
+        buyout.status = BuyoutStatus::Swept;
+        BuyoutStorage::set(env, &buyout);
+
+        if unclaimed > 0 {
+            token::Client::new(env, &buyout.terms.payment_token).transfer(
+                &env.current_contract_address(),
+                &buyout.bidder,
+                &unclaimed,
+            );
+        }
+
+        PropertyEvents::buyout_swept(
+            env,
+            property_id_to_string(env, property_id),
+            buyout.bidder.clone(),
+            unclaimed,
+        );
+
+        unclaimed
+    }
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::contracttype;
+
+/// Storage key types for the buyout module
+#[derive(Clone)]
+#[contracttype]
+pub enum BuyoutKey {
+    /// Buyout of a property
+    /// Storage: Persistent
+    Buyout(u64),
+}
+
+/// TTL bump amounts for buyout storage
+pub mod buyout_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
@@ -0,0 +1,11 @@
+// Buyout module for DeFi RWA
+// This module winds a property down: a bidder deposits payment for every share, trading
+// freezes, holders redeem their shares pro-rata and the property is delisted at the deadline.
+
+mod control;
+mod keys;
+mod state;
+
+pub use control::BuyoutControl;
+pub use keys::{buyout_bump, BuyoutKey};
+pub use state::{Buyout, BuyoutStatus, BuyoutStorage, BuyoutTerms, BUYOUT_CLAIM_PERIOD};
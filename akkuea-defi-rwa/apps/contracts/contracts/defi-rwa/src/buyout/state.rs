@@ -0,0 +1,96 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use super::keys::{buyout_bump, BuyoutKey};
+
+/// Time after the deadline during which holders can still redeem (365 days)
+pub const BUYOUT_CLAIM_PERIOD: u64 = 31_536_000;
+
+/// Payment offered for all of a property's shares
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct BuyoutTerms {
+    /// Token the payment is made in
+    pub payment_token: Address,
+    /// Payment for every issued share
+    pub total_payment: i128,
+    /// Timestamp at which the property is delisted
+    pub deadline: u64,
+}
+
+/// Lifecycle of a buyout
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum BuyoutStatus {
+    /// Trading frozen, holders redeeming
+    Active,
+    /// Deadline passed and property delisted; unclaimed payouts stay in escrow
+    Completed,
+    /// Claim period over; unclaimed payouts returned to the bidder
+    Swept,
+}
+
+/// Buyout of a property
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Buyout {
+    /// Property being bought out
+    pub property_id: u64,
+    /// Account that deposited the payment
+    pub bidder: Address,
+    /// Payment terms
+    pub terms: BuyoutTerms,
+    /// Shares issued when the buyout started
+    pub total_shares: u64,
+    /// Shares redeemed so far
+    pub shares_redeemed: u64,
+    /// Payment paid out so far
+    pub amount_paid_out: i128,
+    /// Current status
+    pub status: BuyoutStatus,
+}
+
+impl Buyout {
+    /// Pro-rata payout for a number of shares
+    pub fn payout(&self, shares: u64) -> i128 {
+        self.terms.total_payment * shares as i128 / self.total_shares as i128
+    }
+
+    /// Payment still held for unredeemed shares
+    pub fn escrow(&self) -> i128 {
+        self.terms.total_payment - self.amount_paid_out
+    }
+
+    /// Timestamp after which unredeemed shares can no longer be paid out
+    pub fn claim_deadline(&self) -> u64 {
+        self.terms.deadline.saturating_add(BUYOUT_CLAIM_PERIOD)
+    }
+}
+
+/// Buyout storage helpers
+pub struct BuyoutStorage;
+
+impl BuyoutStorage {
+    /// Store buyout
+    pub fn set(env: &Env, buyout: &Buyout) {
+        let key = BuyoutKey::Buyout(buyout.property_id);
+        env.storage().persistent().set(&key, buyout);
+        env.storage().persistent().extend_ttl(
+            &key,
+            buyout_bump::PERSISTENT_BUMP,
+            buyout_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the buyout of a property
+    pub fn get(env: &Env, property_id: u64) -> Option<Buyout> {
+        let key = BuyoutKey::Buyout(property_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Check if a property's trading is frozen by an active buyout
+    pub fn is_active(env: &Env, property_id: u64) -> bool {
+        Self::get(env, property_id)
+            .map(|buyout| buyout.status == BuyoutStatus::Active)
+            .unwrap_or(false)
+    }
+}
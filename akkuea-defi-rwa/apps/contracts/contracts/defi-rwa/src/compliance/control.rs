@@ -0,0 +1,117 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::rules::ComplianceStorage;
+use crate::errors::ContractError;
+use crate::storage::property::PropertyMetadata;
+use crate::storage::shares;
+
+/// Compliance checks applied to every share movement
+///
+/// The contract itself (offering escrow, collateral custody) is never
+/// restricted, and the property owner is exempt from everything but freezes
+/// so that issuance and unsold inventory can always return to the issuer.
+pub struct ComplianceControl;
+
+impl ComplianceControl {
+    /// Check that `amount` shares can move from `from` to `to`
+    pub fn require_transfer_allowed(
+        env: &Env,
+        metadata: &PropertyMetadata,
+        from: &Address,
+        to: &Address,
+        amount: u64,
+    ) {
+        Self::require_can_send(env, metadata, from);
+        let balance = shares::get_balance(env, metadata.property_id, to);
+        Self::require_can_receive(env, metadata, to, balance.saturating_add(amount));
+    }
+
+    /// Check that a holder can send shares
+    pub fn require_can_send(env: &Env, metadata: &PropertyMetadata, from: &Address) {
+        if let Err(error) = Self::check_can_send(env, metadata, from) {
+            panic_with_error!(env, error);
+        }
+    }
+
+    /// Check that an account can hold `new_balance` shares
+    pub fn require_can_receive(
+        env: &Env,
+        metadata: &PropertyMetadata,
+        to: &Address,
+        new_balance: u64,
+    ) {
+        if let Err(error) = Self::check_can_receive(env, metadata, to, new_balance) {
+            panic_with_error!(env, error);
+        }
+    }
+
+    /// Whether a holder can send shares, without panicking
+    pub fn check_can_send(
+        env: &Env,
+        metadata: &PropertyMetadata,
+        from: &Address,
+    ) -> Result<(), ContractError> {
+        if *from == env.current_contract_address() {
+            return Ok(());
+        }
+        if ComplianceStorage::is_frozen(env, metadata.property_id, from) {
+            return Err(ContractError::AddressFrozen);
+        }
+        if ComplianceStorage::get_rules(env, metadata.property_id).is_none()
+            || *from == metadata.owner
+        {
+            return Ok(());
+        }
+
+        let locked_until = ComplianceStorage::get_locked_until(env, metadata.property_id, from);
+        if env.ledger().timestamp() < locked_until {
+            return Err(ContractError::SharesLocked);
+        }
+        Ok(())
+    }
+
+    /// Whether an account can hold `new_balance` shares, without panicking
+    pub fn check_can_receive(
+        env: &Env,
+        metadata: &PropertyMetadata,
+        to: &Address,
+        new_balance: u64,
+    ) -> Result<(), ContractError> {
+        if *to == env.current_contract_address() {
+            return Ok(());
+        }
+        if ComplianceStorage::is_frozen(env, metadata.property_id, to) {
+            return Err(ContractError::AddressFrozen);
+        }
+        let Some(rules) = ComplianceStorage::get_rules(env, metadata.property_id) else {
+            return Ok(());
+        };
+        if *to == metadata.owner {
+            return Ok(());
+        }
+
+        let Some(investor) = ComplianceStorage::get_investor(env, metadata.property_id, to) else {
+            return Err(ContractError::NotAllowlisted);
+        };
+        if !rules.allowed_jurisdictions.is_empty()
+            && !rules.allowed_jurisdictions.contains(&investor.jurisdiction)
+        {
+            return Err(ContractError::JurisdictionNotAllowed);
+        }
+        if rules.max_holding > 0 && new_balance > rules.max_holding {
+            return Err(ContractError::HoldingCapExceeded);
+        }
+        Ok(())
+    }
+
+    /// Start a holder's lock-up period after a purchase
+    pub fn start_lockup(env: &Env, property_id: u64, holder: &Address) {
+        let Some(rules) = ComplianceStorage::get_rules(env, property_id) else {
+            return;
+        };
+        if rules.lockup_period > 0 {
+            let until = env.ledger().timestamp() + rules.lockup_period;
+            ComplianceStorage::set_locked_until(env, property_id, holder, until);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Storage key types for the compliance module
+#[derive(Clone)]
+#[contracttype]
+pub enum ComplianceKey {
+    /// Transfer rules of a property; shares are unrestricted without them
+    /// Storage: Persistent
+    Rules(u64),
+
+    /// Allowlisted investor of a property
+    /// Storage: Persistent
+    Investor(u64, Address),
+
+    /// Address whose shares of a property are frozen
+    /// Storage: Persistent
+    Frozen(u64, Address),
+
+    /// Timestamp until which a holder cannot transfer a property's shares
+    /// Storage: Persistent
+    LockedUntil(u64, Address),
+}
+
+/// TTL bump amounts for compliance storage
+pub mod compliance_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
@@ -0,0 +1,11 @@
+// Compliance module for DeFi RWA
+// This module enforces per-property transfer restrictions on shares: a KYC allowlist
+// with jurisdiction tags, per-investor holding caps, post-purchase lock-ups and frozen addresses.
+
+mod control;
+mod keys;
+mod rules;
+
+pub use control::ComplianceControl;
+pub use keys::{compliance_bump, ComplianceKey};
+pub use rules::{ComplianceRules, ComplianceStorage, Investor};
@@ -0,0 +1,100 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use super::keys::{compliance_bump, ComplianceKey};
+
+/// Transfer rules of a property
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ComplianceRules {
+    /// Jurisdictions investors may be tagged with (empty allows any)
+    pub allowed_jurisdictions: Vec<String>,
+    /// Maximum shares a single investor may hold (0 for no cap)
+    pub max_holding: u64,
+    /// Seconds purchased shares stay locked after they are received
+    pub lockup_period: u64,
+}
+
+/// KYC-approved investor of a property
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Investor {
+    /// Jurisdiction tag (e.g. ISO country code)
+    pub jurisdiction: String,
+    /// Timestamp of the KYC approval
+    pub approved_at: u64,
+}
+
+/// Compliance storage helpers
+pub struct ComplianceStorage;
+
+impl ComplianceStorage {
+    /// Get the transfer rules of a property
+    pub fn get_rules(env: &Env, property_id: u64) -> Option<ComplianceRules> {
+        let key = ComplianceKey::Rules(property_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Set the transfer rules of a property
+    pub fn set_rules(env: &Env, property_id: u64, rules: &ComplianceRules) {
+        let key = ComplianceKey::Rules(property_id);
+        Self::set(env, &key, rules);
+    }
+
+    /// Get an allowlisted investor
+    pub fn get_investor(env: &Env, property_id: u64, investor: &Address) -> Option<Investor> {
+        let key = ComplianceKey::Investor(property_id, investor.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Add an investor to the allowlist, replacing any previous entry
+    pub fn set_investor(env: &Env, property_id: u64, address: &Address, investor: &Investor) {
+        let key = ComplianceKey::Investor(property_id, address.clone());
+        Self::set(env, &key, investor);
+    }
+
+    /// Remove an investor from the allowlist
+    pub fn remove_investor(env: &Env, property_id: u64, investor: &Address) {
+        let key = ComplianceKey::Investor(property_id, investor.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Check if an address is frozen for a property
+    pub fn is_frozen(env: &Env, property_id: u64, account: &Address) -> bool {
+        let key = ComplianceKey::Frozen(property_id, account.clone());
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Freeze or unfreeze an address for a property
+    pub fn set_frozen(env: &Env, property_id: u64, account: &Address, frozen: bool) {
+        let key = ComplianceKey::Frozen(property_id, account.clone());
+        if frozen {
+            Self::set(env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Get the end of a holder's lock-up period
+    pub fn get_locked_until(env: &Env, property_id: u64, holder: &Address) -> u64 {
+        let key = ComplianceKey::LockedUntil(property_id, holder.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set the end of a holder's lock-up period
+    pub fn set_locked_until(env: &Env, property_id: u64, holder: &Address, until: u64) {
+        let key = ComplianceKey::LockedUntil(property_id, holder.clone());
+        Self::set(env, &key, &until);
+    }
+
+    fn set<V>(env: &Env, key: &ComplianceKey, value: &V)
+    where
+        V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+    {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(
+            key,
+            compliance_bump::PERSISTENT_BUMP,
+            compliance_bump::PERSISTENT_BUMP,
+        );
+    }
+}
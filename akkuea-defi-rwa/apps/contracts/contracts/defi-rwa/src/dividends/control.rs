@@ -0,0 +1,123 @@
+use soroban_sdk::{panic_with_error, token, Address, Env};
+
+use super::index::DividendStorage;
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, PropertyEvents};
+use crate::lending::PRECISION;
+use crate::storage::property::PropertyMetadata;
+use crate::storage::shares;
+
+/// Dividend distribution and claim flow
+///
+/// Each distribution raises a cumulative dividend-per-share index. A holder's
+/// entitlement is `balance * (index - holder_index)`, settled whenever the
+/// holder's balance changes, so shares moved between distributions only earn
+/// dividends for the time they were held. Shares held by the contract itself
+/// (escrow, collateral) are excluded from distributions.
+pub struct DividendControl;
+
+impl DividendControl {
+    /// Credit a holder with dividends accrued since the last settlement
+    ///
+    /// Must be called before the holder's share balance changes.
+    pub fn settle(env: &Env, property_id: u64, holder: &Address) {
+        if *holder == env.current_contract_address() {
+            return;
+        }
+
+        let index = DividendStorage::get_index(env, property_id);
+        let holder_index = DividendStorage::get_holder_index(env, property_id, holder);
+        if index == holder_index {
+            return;
+        }
+
+        let balance = shares::get_balance(env, property_id, holder) as i128;
+        if balance > 0 {
+            let accrued = balance * (index - holder_index) / PRECISION;
+            let unclaimed = DividendStorage::get_unclaimed(env, property_id, holder);
+            DividendStorage::set_unclaimed(env, property_id, holder, unclaimed + accrued);
+        }
+        DividendStorage::set_holder_index(env, property_id, holder, index);
+    }
+
+    /// Deposit rental income to be shared among current shareholders
+    pub fn distribute(
+        env: &Env,
+        owner: &Address,
+        property_id: u64,
+        payment_token: &Address,
+        amount: i128,
+    ) {
+        let metadata = PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        if metadata.owner != *owner {
+            panic_with_error!(env, ContractError::NotPropertyOwner);
+        }
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        match DividendStorage::get_token(env, property_id) {
+            Some(token) if token != *payment_token => {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            Some(_) => {}
+            None => DividendStorage::set_token(env, property_id, payment_token),
+        }
+
+        let contract = env.current_contract_address();
+        let eligible_shares = shares::get_total_shares(env, property_id)
+            - shares::get_balance(env, property_id, &contract);
+        if eligible_shares == 0 {
+            panic_with_error!(env, ContractError::InsufficientShares);
+        }
+
+        token::Client::new(env, payment_token).transfer(owner, &contract, &amount);
+
+        let per_share_amount = amount * PRECISION / eligible_shares as i128;
+        let index = DividendStorage::get_index(env, property_id);
+        DividendStorage::set_index(env, property_id, index + per_share_amount);
+
+        PropertyEvents::dividend_distributed(
+            env,
+            property_id_to_string(env, property_id),
+            amount,
+            per_share_amount,
+        );
+    }
+
+    /// Dividends a holder could claim right now
+    pub fn claimable(env: &Env, property_id: u64, holder: &Address) -> i128 {
+        if *holder == env.current_contract_address() {
+            return 0;
+        }
+        let index = DividendStorage::get_index(env, property_id);
+        let holder_index = DividendStorage::get_holder_index(env, property_id, holder);
+        let balance = shares::get_balance(env, property_id, holder) as i128;
+        DividendStorage::get_unclaimed(env, property_id, holder)
+            + balance * (index - holder_index) / PRECISION
+    }
+
+    /// Pay out a holder's accrued dividends
+    ///
+    /// Claiming twice is harmless: the second claim pays nothing.
+    pub fn claim(env: &Env, property_id: u64, holder: &Address) -> i128 {
+        Self::settle(env, property_id, holder);
+        let amount = DividendStorage::get_unclaimed(env, property_id, holder);
+        if amount == 0 {
+            return 0;
+        }
+        DividendStorage::set_unclaimed(env, property_id, holder, 0);
+
+        let token = DividendStorage::get_token(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        token::Client::new(env, &token).transfer(&env.current_contract_address(), holder, &amount);
+
+        PropertyEvents::dividend_claimed(
+            env,
+            property_id_to_string(env, property_id),
+            holder.clone(),
+            amount,
+        );
+        amount
+    }
+}
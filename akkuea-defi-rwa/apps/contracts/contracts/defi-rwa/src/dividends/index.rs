@@ -0,0 +1,80 @@
+use soroban_sdk::{Address, Env};
+
+use super::keys::{dividend_bump, DividendKey};
+
+/// Dividend storage helpers
+pub struct DividendStorage;
+
+impl DividendStorage {
+    /// Get the dividend token of a property
+    pub fn get_token(env: &Env, property_id: u64) -> Option<Address> {
+        let key = DividendKey::Token(property_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Set the dividend token of a property
+    pub fn set_token(env: &Env, property_id: u64, token: &Address) {
+        let key = DividendKey::Token(property_id);
+        env.storage().persistent().set(&key, token);
+        env.storage().persistent().extend_ttl(
+            &key,
+            dividend_bump::PERSISTENT_BUMP,
+            dividend_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get cumulative dividend per share
+    pub fn get_index(env: &Env, property_id: u64) -> i128 {
+        let key = DividendKey::Index(property_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set cumulative dividend per share
+    pub fn set_index(env: &Env, property_id: u64, index: i128) {
+        let key = DividendKey::Index(property_id);
+        env.storage().persistent().set(&key, &index);
+        env.storage().persistent().extend_ttl(
+            &key,
+            dividend_bump::PERSISTENT_BUMP,
+            dividend_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the index at which a holder was last settled
+    pub fn get_holder_index(env: &Env, property_id: u64, holder: &Address) -> i128 {
+        let key = DividendKey::HolderIndex(property_id, holder.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set the index at which a holder was last settled
+    pub fn set_holder_index(env: &Env, property_id: u64, holder: &Address, index: i128) {
+        let key = DividendKey::HolderIndex(property_id, holder.clone());
+        env.storage().persistent().set(&key, &index);
+        env.storage().persistent().extend_ttl(
+            &key,
+            dividend_bump::PERSISTENT_BUMP,
+            dividend_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get settled but unclaimed dividends of a holder
+    pub fn get_unclaimed(env: &Env, property_id: u64, holder: &Address) -> i128 {
+        let key = DividendKey::Unclaimed(property_id, holder.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set settled but unclaimed dividends of a holder
+    pub fn set_unclaimed(env: &Env, property_id: u64, holder: &Address, amount: i128) {
+        let key = DividendKey::Unclaimed(property_id, holder.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+            env.storage().persistent().extend_ttl(
+                &key,
+                dividend_bump::PERSISTENT_BUMP,
+                dividend_bump::PERSISTENT_BUMP,
+            );
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Storage key types for the dividends module
+#[derive(Clone)]
+#[contracttype]
+pub enum DividendKey {
+    /// Token in which a property's dividends are paid
+    /// Storage: Persistent
+    Token(u64),
+
+    /// Cumulative dividend per share for a property (PRECISION scaled)
+    /// Storage: Persistent
+    Index(u64),
+
+    /// Dividend index at which a holder was last settled
+    /// Storage: Persistent
+    HolderIndex(u64, Address),
+
+    /// Settled but unclaimed dividends of a holder
+    /// Storage: Persistent
+    Unclaimed(u64, Address),
+}
+
+/// TTL bump amounts for dividend storage
+pub mod dividend_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
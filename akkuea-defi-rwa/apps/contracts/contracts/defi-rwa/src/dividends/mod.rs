@@ -0,0 +1,11 @@
+// Dividends module for DeFi RWA
+// This module distributes rental income to property shareholders pro-rata using a
+// cumulative dividend-per-share index, so share transfers between distributions stay fair.
+
+mod control;
+mod index;
+mod keys;
+
+pub use control::DividendControl;
+pub use index::DividendStorage;
+pub use keys::{dividend_bump, DividendKey};
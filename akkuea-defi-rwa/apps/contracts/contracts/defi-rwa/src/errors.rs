@@ -0,0 +1,137 @@
+//! Contract error codes
+//!
+//! Every failing entry point aborts with one of these codes, so clients and
+//! integrating contracts can tell failure reasons apart. Codes are grouped by
+//! domain and never reused. The contract spec allows at most 50 error cases,
+//! so related failures of different features share a code.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    // Access control
+    /// Contract or admin was already initialized
+    AlreadyInitialized = 1,
+    /// Contract, admin or market has not been initialized
+    NotInitialized = 2,
+    /// Caller is not the admin
+    NotAdmin = 3,
+    /// Caller is not the pending admin
+    NotPendingAdmin = 4,
+    /// Caller lacks the role, ownership or approval required
+    Unauthorized = 5,
+
+    // Pause
+    /// Contract is paused
+    ContractPaused = 10,
+    /// Contract is not paused
+    ContractNotPaused = 11,
+    /// Function is paused contract-wide, for the pool or for the property
+    FunctionPaused = 12,
+
+    // Input validation
+    /// Amount is zero or negative where a positive amount is required
+    InvalidAmount = 20,
+    /// Price is zero or negative
+    InvalidPrice = 21,
+    /// Parameters, terms or flags are out of range
+    InvalidParameters = 22,
+    /// Configuration is out of range
+    InvalidConfig = 23,
+    /// Deadline or expiration is in the past
+    InvalidDeadline = 24,
+
+    // Properties
+    /// Property does not exist
+    PropertyNotFound = 30,
+    /// Property is deactivated
+    PropertyNotActive = 31,
+    /// Property has not been verified
+    PropertyNotVerified = 32,
+    /// Property was already verified
+    PropertyAlreadyVerified = 33,
+    /// Caller is not the property owner
+    NotPropertyOwner = 34,
+    /// Property trading is frozen by a buyout
+    TradingFrozen = 35,
+
+    // Shares
+    /// Account holds too few shares
+    InsufficientShares = 40,
+    /// Spender allowance is too low
+    InsufficientAllowance = 41,
+
+    // Compliance
+    /// Sender or recipient is frozen for the property
+    AddressFrozen = 50,
+    /// Recipient is not on the property's KYC allowlist
+    NotAllowlisted = 51,
+    /// Recipient's jurisdiction is not allowed for the property
+    JurisdictionNotAllowed = 52,
+    /// Recipient would exceed the per-investor holding cap
+    HoldingCapExceeded = 53,
+    /// Sender's shares are still in their lock-up period
+    SharesLocked = 54,
+
+    // Offerings, orders, proposals, buyouts, valuations and timelocked operations
+    /// Record does not exist
+    NotFound = 60,
+    /// Record already exists
+    AlreadyExists = 61,
+    /// Period has not started or not ended yet, or an offering is still open
+    TooEarly = 62,
+    /// Record is already closed, filled, finalized or claimed
+    Closed = 63,
+    /// Account already voted on the proposal or signed off the valuation
+    AlreadyVoted = 64,
+    /// Purchase is outside the offering limits or remaining supply
+    AmountOutOfRange = 65,
+
+    // Lending pools
+    /// Pool does not exist
+    PoolNotFound = 70,
+    /// Pool is not active
+    PoolNotActive = 71,
+    /// Pool has too little liquidity
+    InsufficientLiquidity = 72,
+    /// Pool reserves are too low
+    InsufficientReserves = 73,
+    /// Supply, borrow, per-user borrow, document or order book cap would be exceeded
+    CapExceeded = 74,
+    /// Amount is too small to mint, redeem or liquidate anything
+    AmountTooSmall = 75,
+    /// Flash loan principal and fee were not returned
+    FlashLoanNotRepaid = 76,
+
+    // Positions
+    /// Account has no such position in the pool
+    PositionNotFound = 80,
+    /// Position holds less than requested
+    InsufficientBalance = 81,
+    /// Collateral does not cover the debt
+    InsufficientCollateral = 82,
+    /// Collateral is not whitelisted or differs from the posted asset
+    CollateralNotAccepted = 83,
+    /// Account has no debt to repay
+    NoOutstandingDebt = 84,
+    /// Position is healthy and cannot be liquidated
+    PositionHealthy = 85,
+
+    // Oracle
+    /// Price feed has no price
+    PriceNotAvailable = 90,
+    /// Price is older than the maximum age
+    PriceStale = 91,
+    /// Reported price deviates too far from the current one
+    PriceDeviationTooLarge = 92,
+
+    // Math
+    /// Arithmetic overflow
+    MathOverflow = 100,
+
+    // Upgrades
+    /// Stored records are older than the current storage schema
+    MigrationPending = 110,
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{contractevent, Address, Env};
+
+/// Emitted when the contract-wide pause flags change
+#[contractevent]
+pub struct PauseToggled {
+    pub paused: u32,
+    pub by_admin: Address,
+}
+
+/// Event helper functions for access control
+pub struct AccessEvents;
+
+impl AccessEvents {
+    /// Emitted when the contract-wide pause flags change
+    pub fn pause_toggled(env: &Env, paused: u32, by_admin: Address) {
+        PauseToggled { paused, by_admin }.publish(env);
+    }
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{contractevent, Address, Env, String};
+
+/// Emitted when an investor is added to a property's KYC allowlist
+#[contractevent]
+pub struct InvestorAllowlisted {
+    pub property_id: String,
+    pub investor: Address,
+    pub jurisdiction: String,
+}
+
+/// Emitted when an investor is removed from a property's KYC allowlist
+#[contractevent]
+pub struct InvestorRemoved {
+    pub property_id: String,
+    pub investor: Address,
+}
+
+/// Emitted when an address is frozen or unfrozen for a property
+#[contractevent]
+pub struct AccountFrozen {
+    pub property_id: String,
+    pub account: Address,
+    pub frozen: bool,
+}
+
+/// Event helper functions for compliance operations
+pub struct ComplianceEvents;
+
+impl ComplianceEvents {
+    /// Emitted when an investor is added to a property's KYC allowlist
+    pub fn investor_allowlisted(
+        env: &Env,
+        property_id: String,
+        investor: Address,
+        jurisdiction: String,
+    ) {
+        InvestorAllowlisted {
+            property_id,
+            investor,
+            jurisdiction,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when an investor is removed from a property's KYC allowlist
+    pub fn investor_removed(env: &Env, property_id: String, investor: Address) {
+        InvestorRemoved {
+            property_id,
+            investor,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when an address is frozen or unfrozen for a property
+    pub fn account_frozen(env: &Env, property_id: String, account: Address, frozen: bool) {
+        AccountFrozen {
+            property_id,
+            account,
+            frozen,
+        }
+        .publish(env);
+    }
+}
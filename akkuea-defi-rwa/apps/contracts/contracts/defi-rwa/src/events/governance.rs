@@ -0,0 +1,73 @@
+use soroban_sdk::{contractevent, Address, Env, String};
+
+use crate::governance::ProposalStatus;
+
+/// Emitted when a shareholder proposal is created
+#[contractevent]
+pub struct ProposalCreated {
+    pub property_id: String,
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub snapshot_ledger: u32,
+    pub end_time: u64,
+}
+
+/// Emitted when a shareholder votes on a proposal
+#[contractevent]
+pub struct VoteCast {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub support: bool,
+    pub power: u64,
+}
+
+/// Emitted when a proposal is defeated, approved or executed
+#[contractevent]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+}
+
+/// Event helper functions for governance operations
+pub struct GovernanceEvents;
+
+impl GovernanceEvents {
+    /// Emitted when a shareholder proposal is created
+    pub fn proposal_created(
+        env: &Env,
+        property_id: String,
+        proposal_id: u64,
+        proposer: Address,
+        snapshot_ledger: u32,
+        end_time: u64,
+    ) {
+        ProposalCreated {
+            property_id,
+            proposal_id,
+            proposer,
+            snapshot_ledger,
+            end_time,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a shareholder votes on a proposal
+    pub fn vote_cast(env: &Env, proposal_id: u64, voter: Address, support: bool, power: u64) {
+        VoteCast {
+            proposal_id,
+            voter,
+            support,
+            power,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a proposal is defeated, approved or executed
+    pub fn proposal_finalized(env: &Env, proposal_id: u64, status: ProposalStatus) {
+        ProposalFinalized {
+            proposal_id,
+            status,
+        }
+        .publish(env);
+    }
+}
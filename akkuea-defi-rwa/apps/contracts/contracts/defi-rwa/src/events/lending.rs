@@ -61,6 +61,33 @@ pub struct Liquidation {
     pub penalty: i128,
 }
 
+/// Emitted when a liquidation leaves debt without collateral
+#[contractevent]
+pub struct BadDebtRecorded {
+    pub pool_id: String,
+    pub borrower: Address,
+    pub amount: i128,
+}
+
+/// Emitted when written-off debt is covered by the insurance fund and suppliers
+#[contractevent]
+pub struct ShortfallCovered {
+    pub pool_id: String,
+    pub borrower: Address,
+    pub shortfall: i128,
+    pub insurance_used: i128,
+    pub socialized: i128,
+    pub exchange_rate: i128,
+}
+
+/// Emitted when assets are added to a pool's insurance fund
+#[contractevent]
+pub struct InsuranceFunded {
+    pub pool_id: String,
+    pub funder: Address,
+    pub amount: i128,
+}
+
 /// Emitted when interest is accrued
 #[contractevent]
 pub struct InterestAccrued {
@@ -80,11 +107,53 @@ pub struct PoolUpdated {
     pub new_value: i128,
 }
 
+/// Emitted when deposit receipts change hands
+#[contractevent]
+pub struct ReceiptTransfer {
+    pub pool_id: String,
+    pub from: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a receipt owner approves a spender
+#[contractevent]
+pub struct ReceiptApproval {
+    pub pool_id: String,
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// Emitted when a flash loan is repaid
+#[contractevent]
+pub struct FlashLoan {
+    pub pool_id: String,
+    pub receiver: Address,
+    pub amount: i128,
+    pub fee: i128,
+}
+
+/// Emitted when pool reserves are sent to a treasury
+#[contractevent]
+pub struct ReservesWithdrawn {
+    pub pool_id: String,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a pool switches to a new interest rate model
+#[contractevent]
+pub struct RateModelChanged {
+    pub pool_id: String,
+}
+
 /// Emitted when pool is paused/unpaused
 #[contractevent]
 pub struct PoolPauseToggled {
     pub pool_id: String,
-    pub paused: bool,
+    pub paused: u32,
     pub by_admin: Address,
 }
 
@@ -208,6 +277,16 @@ impl LendingEvents {
         .publish(env);
     }
 
+    /// Emitted when a liquidation leaves debt without collateral
+    pub fn bad_debt_recorded(env: &Env, pool_id: String, borrower: Address, amount: i128) {
+        BadDebtRecorded {
+            pool_id,
+            borrower,
+            amount,
+        }
+        .publish(env);
+    }
+
     /// Emitted when interest is accrued
     pub fn interest_accrued(
         env: &Env,
@@ -243,8 +322,95 @@ impl LendingEvents {
         .publish(env);
     }
 
-    /// Emitted when pool is paused/unpaused
-    pub fn pool_pause_toggled(env: &Env, pool_id: String, paused: bool, by_admin: Address) {
+    /// Emitted when deposit receipts change hands
+    pub fn receipt_transfer(env: &Env, pool_id: String, from: Address, to: Address, amount: i128) {
+        ReceiptTransfer {
+            pool_id,
+            from,
+            to,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a receipt owner approves a spender
+    pub fn receipt_approval(
+        env: &Env,
+        pool_id: String,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        ReceiptApproval {
+            pool_id,
+            owner,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when written-off debt is covered by the insurance fund and suppliers
+    pub fn shortfall_covered(
+        env: &Env,
+        pool_id: String,
+        borrower: Address,
+        shortfall: i128,
+        insurance_used: i128,
+        socialized: i128,
+        exchange_rate: i128,
+    ) {
+        ShortfallCovered {
+            pool_id,
+            borrower,
+            shortfall,
+            insurance_used,
+            socialized,
+            exchange_rate,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when assets are added to a pool's insurance fund
+    pub fn insurance_funded(env: &Env, pool_id: String, funder: Address, amount: i128) {
+        InsuranceFunded {
+            pool_id,
+            funder,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a flash loan is repaid
+    pub fn flash_loan(env: &Env, pool_id: String, receiver: Address, amount: i128, fee: i128) {
+        FlashLoan {
+            pool_id,
+            receiver,
+            amount,
+            fee,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when pool reserves are sent to a treasury
+    pub fn reserves_withdrawn(env: &Env, pool_id: String, treasury: Address, amount: i128) {
+        ReservesWithdrawn {
+            pool_id,
+            treasury,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a pool switches to a new interest rate model
+    pub fn rate_model_changed(env: &Env, pool_id: String) {
+        RateModelChanged { pool_id }.publish(env);
+    }
+
+    /// Emitted when a pool's pause flags change
+    pub fn pool_pause_toggled(env: &Env, pool_id: String, paused: u32, by_admin: Address) {
         PoolPauseToggled {
             pool_id,
             paused,
@@ -0,0 +1,124 @@
+use soroban_sdk::{contractevent, Address, Env, String};
+
+use crate::market::{Order, OrderSide};
+
+/// Emitted when a limit order is placed
+#[contractevent]
+pub struct OrderPlaced {
+    pub property_id: String,
+    pub order_id: u64,
+    pub owner: Address,
+    pub side: OrderSide,
+    pub price: i128,
+    pub amount: i128,
+}
+
+/// Emitted when an order is cancelled and its remainder returned
+#[contractevent]
+pub struct OrderCancelled {
+    pub property_id: String,
+    pub order_id: u64,
+    pub owner: Address,
+    pub returned: i128,
+}
+
+/// Emitted when an order of a frozen owner is pulled from the book
+#[contractevent]
+pub struct OrderSuspended {
+    pub property_id: String,
+    pub order_id: u64,
+    pub owner: Address,
+}
+
+/// Emitted when a buy and a sell order trade
+#[contractevent]
+pub struct TradeExecuted {
+    pub property_id: String,
+    pub buy_order_id: u64,
+    pub sell_order_id: u64,
+    pub buyer: Address,
+    pub seller: Address,
+    pub price: i128,
+    pub shares: i128,
+    pub buyer_fee: i128,
+    pub seller_fee: i128,
+}
+
+/// Event helper functions for secondary market operations
+pub struct MarketEvents;
+
+impl MarketEvents {
+    /// Emitted when a limit order is placed
+    pub fn order_placed(
+        env: &Env,
+        property_id: String,
+        order_id: u64,
+        owner: Address,
+        side: OrderSide,
+        price: i128,
+        amount: i128,
+    ) {
+        OrderPlaced {
+            property_id,
+            order_id,
+            owner,
+            side,
+            price,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when an order is cancelled and its remainder returned
+    pub fn order_cancelled(
+        env: &Env,
+        property_id: String,
+        order_id: u64,
+        owner: Address,
+        returned: i128,
+    ) {
+        OrderCancelled {
+            property_id,
+            order_id,
+            owner,
+            returned,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when an order of a frozen owner is pulled from the book
+    pub fn order_suspended(env: &Env, property_id: String, order_id: u64, owner: Address) {
+        OrderSuspended {
+            property_id,
+            order_id,
+            owner,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a buy and a sell order trade
+    #[allow(clippy::too_many_arguments)]
+    pub fn trade_executed(
+        env: &Env,
+        property_id: String,
+        bid: &Order,
+        ask: &Order,
+        price: i128,
+        shares: i128,
+        buyer_fee: i128,
+        seller_fee: i128,
+    ) {
+        TradeExecuted {
+            property_id,
+            buy_order_id: bid.id,
+            sell_order_id: ask.id,
+            buyer: bid.owner.clone(),
+            seller: ask.owner.clone(),
+            price,
+            shares,
+            buyer_fee,
+            seller_fee,
+        }
+        .publish(env);
+    }
+}
@@ -3,8 +3,20 @@
 //! This module defines all events emitted by the contracts.
 //! Events are indexed and can be queried by off-chain services.
 
+mod access;
+mod compliance;
+mod governance;
 mod lending;
+mod market;
 mod property;
+mod timelock;
+mod upgrade;
 
+pub use access::AccessEvents;
+pub use compliance::ComplianceEvents;
+pub use governance::GovernanceEvents;
 pub use lending::LendingEvents;
-pub use property::PropertyEvents;
+pub use market::MarketEvents;
+pub use property::{property_id_to_string, PropertyEvents};
+pub use timelock::TimelockEvents;
+pub use upgrade::UpgradeEvents;
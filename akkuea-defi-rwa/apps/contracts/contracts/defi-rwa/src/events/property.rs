@@ -1,4 +1,6 @@
-use soroban_sdk::{contractevent, Address, Env, String};
+use soroban_sdk::{contractevent, Address, BytesN, Env, String};
+
+use crate::registry::DocumentType;
 
 /// Emitted when a new property is registered
 #[contractevent]
@@ -27,6 +29,16 @@ pub struct ShareTransfer {
     pub amount: i128,
 }
 
+/// Emitted when a share owner approves a spender
+#[contractevent]
+pub struct ShareApproval {
+    pub property_id: String,
+    pub owner: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
 /// Emitted when shares are purchased from pool
 #[contractevent]
 pub struct SharePurchase {
@@ -36,7 +48,7 @@ pub struct SharePurchase {
     pub total_cost: i128,
 }
 
-/// Emitted when shares are sold back to pool
+/// Emitted when a seller's shares are sold on the secondary market
 #[contractevent]
 pub struct ShareSale {
     pub property_id: String,
@@ -45,6 +57,37 @@ pub struct ShareSale {
     pub proceeds: i128,
 }
 
+/// Emitted when a primary share offering opens
+#[contractevent]
+pub struct OfferingOpened {
+    pub property_id: String,
+    pub offering_id: u64,
+    pub owner: Address,
+    pub payment_token: Address,
+    pub price_per_share: i128,
+    pub shares_offered: i128,
+    pub end_time: u64,
+}
+
+/// Emitted when a primary share offering closes
+#[contractevent]
+pub struct OfferingClosed {
+    pub property_id: String,
+    pub offering_id: u64,
+    pub successful: bool,
+    pub shares_sold: i128,
+    pub proceeds: i128,
+}
+
+/// Emitted when a buyer is refunded after a failed offering
+#[contractevent]
+pub struct OfferingRefunded {
+    pub property_id: String,
+    pub offering_id: u64,
+    pub buyer: Address,
+    pub amount: i128,
+}
+
 /// Emitted when dividends are distributed
 #[contractevent]
 pub struct DividendDistributed {
@@ -62,6 +105,103 @@ pub struct DividendClaimed {
     pub amount: i128,
 }
 
+/// Emitted when a bidder deposits payment for all of a property's shares
+#[contractevent]
+pub struct BuyoutStarted {
+    pub property_id: String,
+    pub bidder: Address,
+    pub total_payment: i128,
+    pub deadline: u64,
+}
+
+/// Emitted when a holder redeems shares for their buyout payout
+#[contractevent]
+pub struct SharesRedeemed {
+    pub property_id: String,
+    pub holder: Address,
+    pub shares: i128,
+    pub payout: i128,
+}
+
+/// Emitted when a bought-out property is delisted
+#[contractevent]
+pub struct PropertyDelisted {
+    pub property_id: String,
+    pub unclaimed: i128,
+}
+
+/// Emitted when unredeemed buyout payouts are returned to the bidder
+#[contractevent]
+pub struct BuyoutSwept {
+    pub property_id: String,
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a property's pause flags change
+#[contractevent]
+pub struct PropertyPauseToggled {
+    pub property_id: String,
+    pub paused: u32,
+    pub by_admin: Address,
+}
+
+/// Emitted when a document is attached to a property
+#[contractevent]
+pub struct DocumentAdded {
+    pub property_id: String,
+    pub document_type: DocumentType,
+    pub content_hash: BytesN<32>,
+    pub issuer: Address,
+}
+
+/// Emitted when a new valuation is proposed for sign-off
+#[contractevent]
+pub struct ValuationProposed {
+    pub property_id: String,
+    pub valuation: i128,
+    pub proposed_by: Address,
+}
+
+/// Emitted when a verifier signs off a pending valuation
+#[contractevent]
+pub struct ValuationApproved {
+    pub property_id: String,
+    pub verifier: Address,
+    pub approvals: u32,
+}
+
+/// Emitted when a signed-off valuation takes effect
+#[contractevent]
+pub struct ValuationUpdated {
+    pub property_id: String,
+    pub old_valuation: i128,
+    pub new_valuation: i128,
+}
+
+/// Emitted when a pending valuation is dropped
+#[contractevent]
+pub struct ValuationRejected {
+    pub property_id: String,
+    pub by: Address,
+}
+
+/// Formats a numeric property ID as the string identifier used in events
+pub fn property_id_to_string(env: &Env, property_id: u64) -> String {
+    let mut buf = [0u8; 20];
+    let mut start = buf.len();
+    let mut value = property_id;
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    String::from_bytes(env, &buf[start..])
+}
+
 /// Event helper functions for property operations
 pub struct PropertyEvents;
 
@@ -112,6 +252,25 @@ impl PropertyEvents {
         .publish(env);
     }
 
+    /// Emitted when a share owner approves a spender
+    pub fn share_approval(
+        env: &Env,
+        property_id: String,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        ShareApproval {
+            property_id,
+            owner,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(env);
+    }
+
     /// Emitted when shares are purchased from pool
     pub fn share_purchase(
         env: &Env,
@@ -129,7 +288,7 @@ impl PropertyEvents {
         .publish(env);
     }
 
-    /// Emitted when shares are sold back to pool
+    /// Emitted when a seller's shares are sold on the secondary market
     pub fn share_sale(
         env: &Env,
         property_id: String,
@@ -146,6 +305,66 @@ impl PropertyEvents {
         .publish(env);
     }
 
+    /// Emitted when a primary share offering opens
+    #[allow(clippy::too_many_arguments)]
+    pub fn offering_opened(
+        env: &Env,
+        property_id: String,
+        offering_id: u64,
+        owner: Address,
+        payment_token: Address,
+        price_per_share: i128,
+        shares_offered: i128,
+        end_time: u64,
+    ) {
+        OfferingOpened {
+            property_id,
+            offering_id,
+            owner,
+            payment_token,
+            price_per_share,
+            shares_offered,
+            end_time,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a primary share offering closes
+    pub fn offering_closed(
+        env: &Env,
+        property_id: String,
+        offering_id: u64,
+        successful: bool,
+        shares_sold: i128,
+        proceeds: i128,
+    ) {
+        OfferingClosed {
+            property_id,
+            offering_id,
+            successful,
+            shares_sold,
+            proceeds,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a buyer is refunded after a failed offering
+    pub fn offering_refunded(
+        env: &Env,
+        property_id: String,
+        offering_id: u64,
+        buyer: Address,
+        amount: i128,
+    ) {
+        OfferingRefunded {
+            property_id,
+            offering_id,
+            buyer,
+            amount,
+        }
+        .publish(env);
+    }
+
     /// Emitted when dividends are distributed
     pub fn dividend_distributed(
         env: &Env,
@@ -171,4 +390,129 @@ impl PropertyEvents {
         }
         .publish(env);
     }
+
+    /// Emitted when a bidder deposits payment for all of a property's shares
+    pub fn buyout_started(
+        env: &Env,
+        property_id: String,
+        bidder: Address,
+        total_payment: i128,
+        deadline: u64,
+    ) {
+        BuyoutStarted {
+            property_id,
+            bidder,
+            total_payment,
+            deadline,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a holder redeems shares for their buyout payout
+    pub fn shares_redeemed(
+        env: &Env,
+        property_id: String,
+        holder: Address,
+        shares: i128,
+        payout: i128,
+    ) {
+        SharesRedeemed {
+            property_id,
+            holder,
+            shares,
+            payout,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a bought-out property is delisted
+    pub fn property_delisted(env: &Env, property_id: String, unclaimed: i128) {
+        PropertyDelisted {
+            property_id,
+            unclaimed,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when unredeemed buyout payouts are returned to the bidder
+    pub fn buyout_swept(env: &Env, property_id: String, bidder: Address, amount: i128) {
+        BuyoutSwept {
+            property_id,
+            bidder,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a property's pause flags change
+    pub fn property_pause_toggled(env: &Env, property_id: String, paused: u32, by_admin: Address) {
+        PropertyPauseToggled {
+            property_id,
+            paused,
+            by_admin,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a document is attached to a property
+    pub fn document_added(
+        env: &Env,
+        property_id: String,
+        document_type: DocumentType,
+        content_hash: BytesN<32>,
+        issuer: Address,
+    ) {
+        DocumentAdded {
+            property_id,
+            document_type,
+            content_hash,
+            issuer,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a new valuation is proposed for sign-off
+    pub fn valuation_proposed(
+        env: &Env,
+        property_id: String,
+        valuation: i128,
+        proposed_by: Address,
+    ) {
+        ValuationProposed {
+            property_id,
+            valuation,
+            proposed_by,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a verifier signs off a pending valuation
+    pub fn valuation_approved(env: &Env, property_id: String, verifier: Address, approvals: u32) {
+        ValuationApproved {
+            property_id,
+            verifier,
+            approvals,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a signed-off valuation takes effect
+    pub fn valuation_updated(
+        env: &Env,
+        property_id: String,
+        old_valuation: i128,
+        new_valuation: i128,
+    ) {
+        ValuationUpdated {
+            property_id,
+            old_valuation,
+            new_valuation,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a pending valuation is dropped
+    pub fn valuation_rejected(env: &Env, property_id: String, by: Address) {
+        ValuationRejected { property_id, by }.publish(env);
+    }
 }
@@ -0,0 +1,49 @@
+use soroban_sdk::{contractevent, Address, Env};
+
+use crate::timelock::TimelockOperation;
+
+/// Emitted when an admin operation is queued
+#[contractevent]
+pub struct OperationQueued {
+    pub operation_id: u64,
+    pub operation: TimelockOperation,
+    pub eta: u64,
+}
+
+/// Emitted when a queued operation is applied
+#[contractevent]
+pub struct OperationExecuted {
+    pub operation_id: u64,
+}
+
+/// Emitted when a queued operation is dropped
+#[contractevent]
+pub struct OperationCancelled {
+    pub operation_id: u64,
+    pub by: Address,
+}
+
+/// Event helper functions for timelocked operations
+pub struct TimelockEvents;
+
+impl TimelockEvents {
+    /// Emitted when an admin operation is queued
+    pub fn operation_queued(env: &Env, operation_id: u64, operation: TimelockOperation, eta: u64) {
+        OperationQueued {
+            operation_id,
+            operation,
+            eta,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when a queued operation is applied
+    pub fn operation_executed(env: &Env, operation_id: u64) {
+        OperationExecuted { operation_id }.publish(env);
+    }
+
+    /// Emitted when a queued operation is dropped
+    pub fn operation_cancelled(env: &Env, operation_id: u64, by: Address) {
+        OperationCancelled { operation_id, by }.publish(env);
+    }
+}
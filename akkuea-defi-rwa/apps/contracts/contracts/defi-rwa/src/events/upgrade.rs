@@ -0,0 +1,33 @@
+use soroban_sdk::{contractevent, BytesN, Env};
+
+/// Emitted when the contract WASM is replaced
+#[contractevent]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub schema_version: u32,
+}
+
+/// Emitted when every stored record reaches a new schema version
+#[contractevent]
+pub struct MigrationCompleted {
+    pub schema_version: u32,
+}
+
+/// Event helper functions for upgrades and migrations
+pub struct UpgradeEvents;
+
+impl UpgradeEvents {
+    /// Emitted when the contract WASM is replaced
+    pub fn contract_upgraded(env: &Env, new_wasm_hash: BytesN<32>, schema_version: u32) {
+        ContractUpgraded {
+            new_wasm_hash,
+            schema_version,
+        }
+        .publish(env);
+    }
+
+    /// Emitted when every stored record reaches a new schema version
+    pub fn migration_completed(env: &Env, schema_version: u32) {
+        MigrationCompleted { schema_version }.publish(env);
+    }
+}
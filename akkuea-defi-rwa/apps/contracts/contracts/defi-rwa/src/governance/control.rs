@@ -0,0 +1,175 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::proposal::{GovernanceStorage, Proposal, ProposalAction, ProposalStatus};
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, GovernanceEvents};
+use crate::oracle::BPS_DENOMINATOR;
+use crate::registry::RegistryControl;
+use crate::storage::property::PropertyMetadata;
+use crate::storage::shares;
+use crate::token::ShareToken;
+
+/// Shareholder governance flow
+///
+/// Voting power is the balance held at the end of the ledger a proposal was
+/// created in. Voting only opens from the next ledger, so moving shares after
+/// the snapshot cannot add votes. Quorum and approval are measured against the
+/// shares held outside contract escrow when the proposal is created.
+pub struct GovernanceControl;
+
+impl GovernanceControl {
+    /// Create a proposal for a property's shareholders
+    ///
+    /// # Returns
+    /// * `u64` - ID of the new proposal
+    pub fn create(env: &Env, proposer: &Address, property_id: u64, action: ProposalAction) -> u64 {
+        ShareToken::load_tradable(env, property_id);
+        match &action {
+            ProposalAction::SellProperty(_, price) if *price <= 0 => {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            ProposalAction::UpdateValuation(valuation) if *valuation <= 0 => {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            ProposalAction::SetDividendPolicy(policy)
+                if policy.payout_ratio_bps as i128 > BPS_DENOMINATOR =>
+            {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            _ => {}
+        }
+        if shares::get_balance(env, property_id, proposer) == 0 {
+            panic_with_error!(env, ContractError::InsufficientShares);
+        }
+
+        let config = GovernanceStorage::get_config(env);
+        let proposal = Proposal {
+            id: GovernanceStorage::next_id(env),
+            property_id,
+            proposer: proposer.clone(),
+            action,
+            snapshot_ledger: env.ledger().sequence(),
+            eligible_supply: shares::get_total_shares(env, property_id)
+                - shares::get_balance(env, property_id, &env.current_contract_address()),
+            end_time: env.ledger().timestamp() + config.voting_period,
+            votes_for: 0,
+            votes_against: 0,
+            status: ProposalStatus::Active,
+        };
+        GovernanceStorage::set_proposal(env, &proposal);
+
+        GovernanceEvents::proposal_created(
+            env,
+            property_id_to_string(env, property_id),
+            proposal.id,
+            proposer.clone(),
+            proposal.snapshot_ledger,
+            proposal.end_time,
+        );
+
+        proposal.id
+    }
+
+    /// Cast a vote with the voter's snapshotted balance
+    ///
+    /// Once the votes in favour clear the approval threshold of the whole
+    /// eligible supply the outcome is settled and the proposal executes
+    /// immediately.
+    ///
+    /// # Returns
+    /// * `u64` - Voting power used
+    pub fn vote(env: &Env, voter: &Address, proposal_id: u64, support: bool) -> u64 {
+        let mut proposal = GovernanceStorage::get_proposal(env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if proposal.status != ProposalStatus::Active
+            || env.ledger().timestamp() >= proposal.end_time
+        {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        if env.ledger().sequence() <= proposal.snapshot_ledger {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+        if GovernanceStorage::has_voted(env, proposal_id, voter) {
+            panic_with_error!(env, ContractError::AlreadyVoted);
+        }
+        let power =
+            shares::get_balance_at(env, proposal.property_id, voter, proposal.snapshot_ledger);
+        if power == 0 {
+            panic_with_error!(env, ContractError::InsufficientShares);
+        }
+
+        if support {
+            proposal.votes_for += power;
+        } else {
+            proposal.votes_against += power;
+        }
+        GovernanceStorage::set_voted(env, proposal_id, voter);
+
+        GovernanceEvents::vote_cast(env, proposal_id, voter.clone(), support, power);
+
+        let config = GovernanceStorage::get_config(env);
+        if proposal.has_quorum(&config, proposal.eligible_supply)
+            && proposal.is_approved_by(&config, proposal.eligible_supply)
+        {
+            Self::apply(env, &mut proposal);
+        }
+        GovernanceStorage::set_proposal(env, &proposal);
+
+        power
+    }
+
+    /// Finalize a proposal once voting has ended, executing it if approved
+    pub fn execute(env: &Env, proposal_id: u64) -> ProposalStatus {
+        let mut proposal = GovernanceStorage::get_proposal(env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if proposal.status != ProposalStatus::Active {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        if env.ledger().timestamp() < proposal.end_time {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+
+        let config = GovernanceStorage::get_config(env);
+        if proposal.has_quorum(&config, proposal.eligible_supply)
+            && proposal.is_approved_by(&config, proposal.votes_cast())
+        {
+            Self::apply(env, &mut proposal);
+        } else {
+            proposal.status = ProposalStatus::Defeated;
+            GovernanceEvents::proposal_finalized(env, proposal.id, proposal.status.clone());
+        }
+        GovernanceStorage::set_proposal(env, &proposal);
+
+        proposal.status
+    }
+
+    /// Carry out an approved proposal
+    fn apply(env: &Env, proposal: &mut Proposal) {
+        let mut metadata = PropertyMetadata::load(env, proposal.property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        proposal.status = match &proposal.action {
+            ProposalAction::SellProperty(_, _) => ProposalStatus::Approved,
+            ProposalAction::ChangeManager(manager) => {
+                metadata.owner = manager.clone();
+                metadata.save(env);
+                ProposalStatus::Executed
+            }
+            ProposalAction::UpdateValuation(valuation) => {
+                RegistryControl::submit_valuation(
+                    env,
+                    &proposal.proposer,
+                    proposal.property_id,
+                    *valuation,
+                    None,
+                );
+                ProposalStatus::Executed
+            }
+            ProposalAction::SetDividendPolicy(policy) => {
+                GovernanceStorage::set_dividend_policy(env, proposal.property_id, policy);
+                ProposalStatus::Executed
+            }
+        };
+
+        GovernanceEvents::proposal_finalized(env, proposal.id, proposal.status.clone());
+    }
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Storage key types for the governance module
+#[derive(Clone)]
+#[contracttype]
+pub enum GovernanceKey {
+    /// Voting period, quorum and approval threshold
+    /// Storage: Instance
+    GovernanceConfig,
+
+    /// Proposal by proposal ID
+    /// Storage: Persistent
+    Proposal(u64),
+
+    /// Whether an account voted on a proposal
+    /// Storage: Persistent
+    Voted(u64, Address),
+
+    /// Dividend policy adopted by a property's shareholders
+    /// Storage: Persistent
+    DividendPolicy(u64),
+
+    /// Counter used to generate proposal IDs
+    /// Storage: Instance
+    ProposalCounter,
+}
+
+/// TTL bump amounts for governance storage
+pub mod governance_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
@@ -0,0 +1,13 @@
+// Governance module for DeFi RWA
+// This module lets a property's shareholders vote on decisions about the underlying asset,
+// weighting votes by share balances snapshotted when the proposal is created.
+
+mod control;
+mod keys;
+mod proposal;
+
+pub use control::GovernanceControl;
+pub use keys::{governance_bump, GovernanceKey};
+pub use proposal::{
+    DividendPolicy, GovernanceConfig, GovernanceStorage, Proposal, ProposalAction, ProposalStatus,
+};
@@ -0,0 +1,203 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use super::keys::{governance_bump, GovernanceKey};
+use crate::errors::ContractError;
+use crate::oracle::BPS_DENOMINATOR;
+
+/// Protocol-wide governance configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct GovernanceConfig {
+    /// Seconds a proposal stays open for voting
+    pub voting_period: u64,
+    /// Share of the eligible supply that must vote, in basis points
+    pub quorum_bps: u32,
+    /// Share of the votes cast that must be in favour, in basis points
+    pub approval_threshold_bps: u32,
+}
+
+impl Default for GovernanceConfig {
+    /// Create default governance configuration
+    /// Voting period: 7 days, quorum: 20%, approval: more than 50%
+    fn default() -> Self {
+        Self {
+            voting_period: 604_800,
+            quorum_bps: 2_000,
+            approval_threshold_bps: 5_000,
+        }
+    }
+}
+
+/// Rules for paying out a property's rental income
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DividendPolicy {
+    /// Share of net rental income distributed to holders, in basis points
+    pub payout_ratio_bps: u32,
+    /// Seconds between distributions
+    pub distribution_interval: u64,
+}
+
+/// Decision put to a property's shareholders
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ProposalAction {
+    /// Sell the property to a buyer, who then buys out every share for at
+    /// least the price
+    SellProperty(Address, i128),
+    /// Hand the property over to a new manager
+    ChangeManager(Address),
+    /// Submit a new valuation of the property for verifier sign-off; execution
+    /// fails while another valuation is still awaiting sign-off
+    UpdateValuation(i128),
+    /// Adopt a dividend policy
+    SetDividendPolicy(DividendPolicy),
+}
+
+/// Lifecycle of a proposal
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ProposalStatus {
+    /// Open for voting
+    Active,
+    /// Missed quorum or approval threshold
+    Defeated,
+    /// Approved; the action is carried out off-chain
+    Approved,
+    /// Approved and applied to contract state
+    Executed,
+}
+
+/// Share-weighted proposal scoped to a property
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    /// Unique proposal identifier
+    pub id: u64,
+    /// Property the decision concerns
+    pub property_id: u64,
+    /// Account that created the proposal
+    pub proposer: Address,
+    /// Decision put to vote
+    pub action: ProposalAction,
+    /// Ledger whose closing balances give the voting power
+    pub snapshot_ledger: u32,
+    /// Shares outside contract escrow when the proposal was created, which
+    /// quorum and approval are measured against
+    pub eligible_supply: u64,
+    /// Timestamp at which voting ends
+    pub end_time: u64,
+    /// Shares voted in favour
+    pub votes_for: u64,
+    /// Shares voted against
+    pub votes_against: u64,
+    /// Current status
+    pub status: ProposalStatus,
+}
+
+impl Proposal {
+    /// Shares voted either way
+    pub fn votes_cast(&self) -> u64 {
+        self.votes_for + self.votes_against
+    }
+
+    /// Whether enough of the eligible supply voted
+    pub fn has_quorum(&self, config: &GovernanceConfig, eligible_supply: u64) -> bool {
+        self.votes_cast() as u128 * BPS_DENOMINATOR as u128
+            >= eligible_supply as u128 * config.quorum_bps as u128
+    }
+
+    /// Whether the votes in favour clear the approval threshold of `votes`
+    pub fn is_approved_by(&self, config: &GovernanceConfig, votes: u64) -> bool {
+        self.votes_for as u128 * BPS_DENOMINATOR as u128
+            > votes as u128 * config.approval_threshold_bps as u128
+    }
+}
+
+/// Governance storage helpers
+pub struct GovernanceStorage;
+
+impl GovernanceStorage {
+    /// Get governance configuration
+    pub fn get_config(env: &Env) -> GovernanceConfig {
+        env.storage()
+            .instance()
+            .get(&GovernanceKey::GovernanceConfig)
+            .unwrap_or_default()
+    }
+
+    /// Store governance configuration
+    pub fn set_config(env: &Env, config: &GovernanceConfig) {
+        if config.voting_period == 0
+            || config.quorum_bps as i128 > BPS_DENOMINATOR
+            || config.approval_threshold_bps as i128 >= BPS_DENOMINATOR
+        {
+            panic_with_error!(env, ContractError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&GovernanceKey::GovernanceConfig, config);
+    }
+
+    /// Generate the next proposal ID
+    pub fn next_id(env: &Env) -> u64 {
+        let key = GovernanceKey::ProposalCounter;
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        env.storage().instance().set(&key, &next);
+        next
+    }
+
+    /// Store proposal
+    pub fn set_proposal(env: &Env, proposal: &Proposal) {
+        let key = GovernanceKey::Proposal(proposal.id);
+        env.storage().persistent().set(&key, proposal);
+        env.storage().persistent().extend_ttl(
+            &key,
+            governance_bump::PERSISTENT_BUMP,
+            governance_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get proposal by ID
+    pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+        let key = GovernanceKey::Proposal(proposal_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Check if an account voted on a proposal
+    pub fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
+        let key = GovernanceKey::Voted(proposal_id, voter.clone());
+        env.storage().persistent().has(&key)
+    }
+
+    /// Record that an account voted on a proposal
+    pub fn set_voted(env: &Env, proposal_id: u64, voter: &Address) {
+        let key = GovernanceKey::Voted(proposal_id, voter.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            governance_bump::PERSISTENT_BUMP,
+            governance_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the dividend policy of a property
+    pub fn get_dividend_policy(env: &Env, property_id: u64) -> Option<DividendPolicy> {
+        let key = GovernanceKey::DividendPolicy(property_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Store the dividend policy of a property
+    pub fn set_dividend_policy(env: &Env, property_id: u64, policy: &DividendPolicy) {
+        let key = GovernanceKey::DividendPolicy(property_id);
+        env.storage().persistent().set(&key, policy);
+        env.storage().persistent().extend_ttl(
+            &key,
+            governance_bump::PERSISTENT_BUMP,
+            governance_bump::PERSISTENT_BUMP,
+        );
+    }
+}
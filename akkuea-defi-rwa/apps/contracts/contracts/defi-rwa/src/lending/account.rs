@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+use super::control::LendingControl;
+use super::interest::PRECISION;
+use super::pool::PoolStorage;
+use super::positions::PositionStorage;
+use crate::errors::ContractError;
+use crate::oracle::PriceOracle;
+
+/// Aggregated risk view of an account across every pool it participates in
+///
+/// All values are in base currency. Collateral posted in any pool backs the
+/// debt of every pool, weighted by the risk parameters of the pool it sits in.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AccountSummary {
+    /// Value of the assets supplied to pools
+    pub total_supplied_value: i128,
+    /// Value of the posted collateral
+    pub total_collateral_value: i128,
+    /// Value of the outstanding debt, including accrued interest
+    pub total_debt_value: i128,
+    /// Collateral value weighted by each pool's collateral factor
+    pub borrow_capacity: i128,
+    /// Collateral value weighted by each pool's liquidation threshold
+    pub liquidation_capacity: i128,
+    /// Account health factor (PRECISION scaled, `i128::MAX` without debt)
+    pub health_factor: i128,
+}
+
+impl LendingControl {
+    /// Aggregate an account's supplied, collateral and debt values across pools
+    pub fn account_summary(env: &Env, user: &Address) -> AccountSummary {
+        let mut total_supplied_value = 0;
+        for pool_id in PositionStorage::get_user_deposits(env, user).iter() {
+            let (Some(pool), Some(position)) = (
+                PoolStorage::get(env, &pool_id),
+                PositionStorage::get_deposit(env, user, &pool_id),
+            ) else {
+                continue;
+            };
+            let amount = PoolStorage::shares_to_amount(
+                PoolStorage::get_total_deposits(env, &pool_id),
+                PoolStorage::get_total_shares(env, &pool_id),
+                position.shares,
+            );
+            if amount > 0 {
+                total_supplied_value +=
+                    amount * PriceOracle::asset_price(env, &pool.asset_address) / PRECISION;
+            }
+        }
+
+        let mut total_collateral_value = 0;
+        let mut total_debt_value = 0;
+        let mut borrow_capacity = 0;
+        let mut liquidation_capacity = 0;
+        for pool_id in PositionStorage::get_user_borrows(env, user).iter() {
+            let (Some(pool), Some(position)) = (
+                PoolStorage::get(env, &pool_id),
+                PositionStorage::get_borrow(env, user, &pool_id),
+            ) else {
+                continue;
+            };
+            let (collateral_value, debt_value) = Self::position_values(env, &pool, &position);
+            total_collateral_value += collateral_value;
+            total_debt_value += debt_value;
+            borrow_capacity += collateral_value * pool.collateral_factor / PRECISION;
+            liquidation_capacity += collateral_value * pool.liquidation_threshold / PRECISION;
+        }
+
+        AccountSummary {
+            total_supplied_value,
+            total_collateral_value,
+            total_debt_value,
+            borrow_capacity,
+            liquidation_capacity,
+            health_factor: PositionStorage::calculate_health_factor(
+                liquidation_capacity,
+                total_debt_value,
+                PRECISION,
+            ),
+        }
+    }
+
+    /// Account health factor (PRECISION scaled)
+    ///
+    /// The account can be liquidated once this drops below `PRECISION`.
+    pub fn account_health(env: &Env, user: &Address) -> i128 {
+        Self::account_summary(env, user).health_factor
+    }
+
+    /// Check that an account's total debt stays within its borrow capacity
+    ///
+    /// # Returns
+    /// * `i128` - Account health factor (PRECISION scaled)
+    pub fn require_within_borrow_capacity(env: &Env, user: &Address) -> i128 {
+        let summary = Self::account_summary(env, user);
+        if summary.total_debt_value > summary.borrow_capacity {
+            panic_with_error!(env, ContractError::InsufficientCollateral);
+        }
+        summary.health_factor
+    }
+
+    /// Largest amount an account can still borrow from a pool
+    ///
+    /// Limited by both the account's spare borrow capacity and the pool's
+    /// available liquidity.
+    pub fn max_borrowable(env: &Env, user: &Address, pool_id: &String) -> i128 {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        let summary = Self::account_summary(env, user);
+        let spare_capacity = summary.borrow_capacity - summary.total_debt_value;
+        if spare_capacity <= 0 {
+            return 0;
+        }
+
+        let by_collateral =
+            spare_capacity * PRECISION / PriceOracle::asset_price(env, &pool.asset_address);
+        let liquidity = PoolStorage::calculate_available_liquidity(
+            PoolStorage::get_total_deposits(env, pool_id),
+            PoolStorage::get_total_borrows(env, pool_id),
+        );
+        by_collateral.min(liquidity)
+    }
+
+    /// Check whether an account has outstanding debt outside the given pool
+    pub fn has_debt_elsewhere(env: &Env, user: &Address, pool_id: &String) -> bool {
+        PositionStorage::get_user_borrows(env, user)
+            .iter()
+            .filter(|id| id != pool_id)
+            .any(|id| {
+                PositionStorage::get_borrow(env, user, &id)
+                    .map(|position| position.principal > 0)
+                    .unwrap_or(false)
+            })
+    }
+}
@@ -0,0 +1,233 @@
+use soroban_sdk::{panic_with_error, token, Address, Env, String};
+
+use super::collateral::Collateral;
+use super::control::LendingControl;
+use super::interest::{InterestStorage, PRECISION};
+use super::pool::{LendingPool, PoolStorage};
+use super::positions::{BorrowPosition, PositionStorage};
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+use crate::oracle::PriceOracle;
+
+impl LendingControl {
+    /// Collateral and debt value of a position in base currency
+    pub fn position_values(
+        env: &Env,
+        pool: &LendingPool,
+        position: &BorrowPosition,
+    ) -> (i128, i128) {
+        let collateral_value = if position.collateral_amount > 0 {
+            position.collateral().value(env, position.collateral_amount)
+        } else {
+            0
+        };
+        let debt = PositionStorage::calculate_current_debt(env, position);
+        let debt_value = if debt > 0 {
+            debt * PriceOracle::asset_price(env, &pool.asset_address) / PRECISION
+        } else {
+            0
+        };
+        (collateral_value, debt_value)
+    }
+
+    /// Capitalize the interest a position accrued since it was last touched
+    ///
+    /// The pool-level accrual already credited that interest to total
+    /// borrows, lenders and reserves; this only rebases the position on the
+    /// current index.
+    pub fn settle_interest(env: &Env, pool: &LendingPool, position: &mut BorrowPosition) {
+        position.principal = PositionStorage::calculate_current_debt(env, position);
+        position.index_at_borrow = InterestStorage::get_interest_index(env, &pool.id);
+    }
+
+    /// Lock collateral for a borrow position, opening it if needed
+    pub fn add_collateral(
+        env: &Env,
+        borrower: &Address,
+        pool_id: &String,
+        collateral: Collateral,
+        amount: i128,
+    ) {
+        let pool = Self::load_active_pool(env, pool_id);
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        collateral.require_accepted(env);
+        Self::accrue_interest(env, &pool);
+
+        let mut position = match PositionStorage::get_borrow(env, borrower, pool_id) {
+            Some(position) => {
+                if position.collateral_amount > 0 && position.collateral() != collateral {
+                    panic_with_error!(env, ContractError::CollateralNotAccepted);
+                }
+                position
+            }
+            None => BorrowPosition {
+                pool_id: pool_id.clone(),
+                borrower: borrower.clone(),
+                principal: 0,
+                index_at_borrow: InterestStorage::get_interest_index(env, pool_id),
+                collateral_amount: 0,
+                collateral_asset: collateral.asset_address(env),
+                collateral_property: collateral.property_id(),
+                collateral_pool: collateral.pool_id(),
+                borrowed_at: env.ledger().timestamp(),
+            },
+        };
+
+        collateral.lock(env, borrower, amount);
+
+        position.collateral_asset = collateral.asset_address(env);
+        position.collateral_property = collateral.property_id();
+        position.collateral_pool = collateral.pool_id();
+        position.collateral_amount += amount;
+        PositionStorage::set_borrow(env, &position);
+    }
+
+    /// Unlock collateral as long as the account stays within its borrow capacity
+    pub fn remove_collateral(env: &Env, borrower: &Address, pool_id: &String, amount: i128) {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+        let mut position = PositionStorage::get_borrow(env, borrower, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PositionNotFound));
+        if amount > position.collateral_amount {
+            panic_with_error!(env, ContractError::InsufficientBalance);
+        }
+
+        position.collateral_amount -= amount;
+        if position.collateral_amount == 0 && position.principal == 0 {
+            PositionStorage::remove_borrow(env, borrower, pool_id);
+        } else {
+            PositionStorage::set_borrow(env, &position);
+        }
+        Self::require_within_borrow_capacity(env, borrower);
+
+        position.collateral().release(env, borrower, amount);
+    }
+
+    /// Borrow pool assets against the account's collateral in any pool
+    ///
+    /// # Returns
+    /// * `i128` - Account health factor after the borrow (PRECISION scaled)
+    pub fn borrow(env: &Env, borrower: &Address, pool_id: &String, amount: i128) -> i128 {
+        let pool = Self::load_active_pool(env, pool_id);
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+        let mut position =
+            PositionStorage::get_borrow(env, borrower, pool_id).unwrap_or(BorrowPosition {
+                pool_id: pool_id.clone(),
+                borrower: borrower.clone(),
+                principal: 0,
+                index_at_borrow: InterestStorage::get_interest_index(env, pool_id),
+                collateral_amount: 0,
+                collateral_asset: pool.asset_address.clone(),
+                collateral_property: None,
+                collateral_pool: None,
+                borrowed_at: env.ledger().timestamp(),
+            });
+
+        Self::settle_interest(env, &pool, &mut position);
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let total_borrows = PoolStorage::get_total_borrows(env, pool_id);
+        if amount > PoolStorage::calculate_available_liquidity(total_deposits, total_borrows) {
+            panic_with_error!(env, ContractError::InsufficientLiquidity);
+        }
+        let caps = PoolStorage::get_caps(env, pool_id);
+        if caps.borrow_cap > 0 && total_borrows + amount > caps.borrow_cap {
+            panic_with_error!(env, ContractError::CapExceeded);
+        }
+        if caps.user_borrow_cap > 0 && position.principal + amount > caps.user_borrow_cap {
+            panic_with_error!(env, ContractError::CapExceeded);
+        }
+
+        position.principal += amount;
+        position.borrowed_at = env.ledger().timestamp();
+
+        PositionStorage::set_borrow(env, &position);
+        PoolStorage::set_total_borrows(env, pool_id, total_borrows + amount);
+        let health_factor = Self::require_within_borrow_capacity(env, borrower);
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            &env.current_contract_address(),
+            borrower,
+            &amount,
+        );
+
+        LendingEvents::borrow(
+            env,
+            pool_id.clone(),
+            borrower.clone(),
+            amount,
+            position.collateral_amount,
+            position.collateral_asset.clone(),
+            health_factor,
+        );
+
+        health_factor
+    }
+
+    /// Repay part or all of a position's debt
+    ///
+    /// Amounts above the outstanding debt are capped. A fully repaid position
+    /// releases its collateral and is closed, unless the account still owes
+    /// other pools that the collateral may be backing.
+    ///
+    /// # Returns
+    /// * `i128` - Debt remaining after the repayment
+    pub fn repay(env: &Env, borrower: &Address, pool_id: &String, amount: i128) -> i128 {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+        let mut position = PositionStorage::get_borrow(env, borrower, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PositionNotFound));
+
+        Self::settle_interest(env, &pool, &mut position);
+        if position.principal == 0 {
+            panic_with_error!(env, ContractError::NoOutstandingDebt);
+        }
+        let paid = amount.min(position.principal);
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            borrower,
+            env.current_contract_address(),
+            &paid,
+        );
+
+        position.principal -= paid;
+        let total_borrows = PoolStorage::get_total_borrows(env, pool_id);
+        PoolStorage::set_total_borrows(env, pool_id, (total_borrows - paid).max(0));
+
+        let mut collateral_released = 0;
+        if position.principal == 0 && !Self::has_debt_elsewhere(env, borrower, pool_id) {
+            collateral_released = position.collateral_amount;
+            PositionStorage::remove_borrow(env, borrower, pool_id);
+            if collateral_released > 0 {
+                position
+                    .collateral()
+                    .release(env, borrower, collateral_released);
+            }
+        } else {
+            PositionStorage::set_borrow(env, &position);
+        }
+
+        LendingEvents::repay(
+            env,
+            pool_id.clone(),
+            borrower.clone(),
+            paid,
+            position.principal,
+            collateral_released,
+        );
+
+        position.principal
+    }
+}
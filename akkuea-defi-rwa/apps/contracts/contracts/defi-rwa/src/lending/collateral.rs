@@ -0,0 +1,160 @@
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, String};
+
+use super::interest::PRECISION;
+use super::keys::LendingKey;
+use super::pool::PoolStorage;
+use super::receipt::ReceiptToken;
+use crate::errors::ContractError;
+use crate::oracle::PriceOracle;
+use crate::storage::property::PropertyMetadata;
+use crate::token::ShareToken;
+
+/// Collateral accepted by lending pools
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Collateral {
+    /// Token whitelisted by the admin
+    Token(Address),
+    /// Shares of a property tokenized by this contract
+    PropertyShares(u64),
+    /// Deposit receipts of a lending pool
+    PoolReceipts(String),
+}
+
+impl Collateral {
+    /// Address recorded as `collateral_asset` in borrow positions
+    ///
+    /// Property shares and pool receipts live in this contract, so its own
+    /// address is used.
+    pub fn asset_address(&self, env: &Env) -> Address {
+        match self {
+            Collateral::Token(token) => token.clone(),
+            Collateral::PropertyShares(_) | Collateral::PoolReceipts(_) => {
+                env.current_contract_address()
+            }
+        }
+    }
+
+    /// Property ID for share collateral
+    pub fn property_id(&self) -> Option<u64> {
+        match self {
+            Collateral::PropertyShares(property_id) => Some(*property_id),
+            _ => None,
+        }
+    }
+
+    /// Pool ID for receipt collateral
+    pub fn pool_id(&self) -> Option<String> {
+        match self {
+            Collateral::PoolReceipts(pool_id) => Some(pool_id.clone()),
+            _ => None,
+        }
+    }
+
+    /// Check that the collateral is accepted
+    pub fn require_accepted(&self, env: &Env) {
+        match self {
+            Collateral::Token(token) => {
+                if !CollateralStorage::is_whitelisted(env, token) {
+                    panic_with_error!(env, ContractError::CollateralNotAccepted);
+                }
+            }
+            Collateral::PropertyShares(property_id) => {
+                let metadata = PropertyMetadata::load(env, *property_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+                if !metadata.is_verified {
+                    panic_with_error!(env, ContractError::PropertyNotVerified);
+                }
+            }
+            Collateral::PoolReceipts(pool_id) => {
+                PoolStorage::get(env, pool_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+            }
+        }
+    }
+
+    /// Value of one collateral unit in base currency (PRECISION scaled)
+    pub fn unit_price(&self, env: &Env) -> i128 {
+        match self {
+            Collateral::Token(token) => PriceOracle::asset_price(env, token),
+            Collateral::PropertyShares(property_id) => PriceOracle::share_price(env, *property_id),
+            Collateral::PoolReceipts(pool_id) => {
+                let pool = PoolStorage::get(env, pool_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+                ReceiptToken::exchange_rate(env, pool_id)
+                    * PriceOracle::asset_price(env, &pool.asset_address)
+                    / PRECISION
+            }
+        }
+    }
+
+    /// Value of a collateral amount in base currency
+    pub fn value(&self, env: &Env, amount: i128) -> i128 {
+        amount * self.unit_price(env) / PRECISION
+    }
+
+    /// Move collateral from its owner into the contract
+    pub fn lock(&self, env: &Env, from: &Address, amount: i128) {
+        let contract = env.current_contract_address();
+        match self {
+            Collateral::Token(token) => {
+                token::Client::new(env, token).transfer(from, &contract, &amount);
+            }
+            Collateral::PropertyShares(property_id) => {
+                ShareToken::transfer(
+                    env,
+                    *property_id,
+                    from,
+                    &contract,
+                    ShareToken::to_shares(env, amount),
+                );
+            }
+            Collateral::PoolReceipts(pool_id) => {
+                ReceiptToken::transfer(env, pool_id, from, &contract, amount);
+            }
+        }
+    }
+
+    /// Move collateral out of the contract
+    pub fn release(&self, env: &Env, to: &Address, amount: i128) {
+        let contract = env.current_contract_address();
+        match self {
+            Collateral::Token(token) => {
+                token::Client::new(env, token).transfer(&contract, to, &amount);
+            }
+            Collateral::PropertyShares(property_id) => {
+                ShareToken::transfer(
+                    env,
+                    *property_id,
+                    &contract,
+                    to,
+                    ShareToken::to_shares(env, amount),
+                );
+            }
+            Collateral::PoolReceipts(pool_id) => {
+                ReceiptToken::transfer(env, pool_id, &contract, to, amount);
+            }
+        }
+    }
+}
+
+/// Collateral whitelist storage helpers
+pub struct CollateralStorage;
+
+impl CollateralStorage {
+    /// Check if a token is accepted as collateral
+    pub fn is_whitelisted(env: &Env, token: &Address) -> bool {
+        let key = LendingKey::CollateralToken(token.clone());
+        env.storage().instance().get(&key).unwrap_or(false)
+    }
+
+    /// Add or remove a token from the collateral whitelist
+    pub fn set_whitelisted(env: &Env, token: &Address, enabled: bool) {
+        let key = LendingKey::CollateralToken(token.clone());
+        if enabled {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+}
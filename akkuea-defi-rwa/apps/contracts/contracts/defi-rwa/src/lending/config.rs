@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, panic_with_error, Env};
+
+use super::control::MAX_RESERVE_FACTOR;
+use super::interest::PRECISION;
+use super::keys::LendingKey;
+use crate::errors::ContractError;
+
+/// Protocol-wide lending configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LendingConfig {
+    /// Maximum share of a position's debt repayable in one liquidation (PRECISION scaled)
+    pub close_factor: i128,
+    /// Flash loan fee in basis points of the borrowed amount
+    pub flash_loan_fee_bps: u32,
+    /// Share of new reserves set aside in the pool's insurance fund, in basis points
+    pub insurance_share_bps: u32,
+}
+
+impl Default for LendingConfig {
+    /// Create default lending configuration
+    /// Close factor: 50%, flash loan fee: 0.09%, insurance share: none
+    fn default() -> Self {
+        Self {
+            close_factor: PRECISION / 2,
+            flash_loan_fee_bps: 9,
+            insurance_share_bps: 0,
+        }
+    }
+}
+
+/// Lending configuration storage helpers
+pub struct LendingConfigStorage;
+
+impl LendingConfigStorage {
+    /// Get lending configuration
+    pub fn get(env: &Env) -> LendingConfig {
+        env.storage()
+            .instance()
+            .get(&LendingKey::LendingConfig)
+            .unwrap_or_default()
+    }
+
+    /// Store lending configuration
+    pub fn set(env: &Env, config: &LendingConfig) {
+        if config.close_factor <= 0
+            || config.close_factor > PRECISION
+            || config.flash_loan_fee_bps > MAX_RESERVE_FACTOR
+            || config.insurance_share_bps > MAX_RESERVE_FACTOR
+        {
+            panic_with_error!(env, ContractError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&LendingKey::LendingConfig, config);
+    }
+}
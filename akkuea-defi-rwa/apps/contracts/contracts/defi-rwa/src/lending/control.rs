@@ -0,0 +1,418 @@
+use soroban_sdk::{panic_with_error, token, Address, Env, String};
+
+use super::interest::{InterestRateModel, InterestStorage, PRECISION};
+use super::pool::{LendingPool, PoolCaps, PoolRiskParams, PoolStorage};
+use super::positions::{DepositPosition, PositionStorage};
+use crate::access::PauseControl;
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+
+/// Maximum reserve factor in basis points (100%)
+pub const MAX_RESERVE_FACTOR: u32 = 10_000;
+
+/// Lending pool operations
+///
+/// Deposits mint pool shares at the pool's current exchange rate
+/// (`total_deposits / total_shares`), so interest credited to
+/// `total_deposits` is shared by all depositors.
+pub struct LendingControl;
+
+impl LendingControl {
+    /// Load a pool that accepts new operations
+    pub fn load_active_pool(env: &Env, pool_id: &String) -> LendingPool {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if !pool.is_active {
+            panic_with_error!(env, ContractError::PoolNotActive);
+        }
+        pool
+    }
+
+    /// Advance the pool's borrow index to the current ledger time
+    ///
+    /// Interest accrued on total borrows since the last accrual is split
+    /// between lenders (`total_deposits`) and reserves using the reserve factor.
+    /// Adaptive rate models are moved for the elapsed period afterwards.
+    ///
+    /// # Returns
+    /// * `i128` - Interest accrued
+    pub fn accrue_interest(env: &Env, pool: &LendingPool) -> i128 {
+        let now = env.ledger().timestamp();
+        let last_accrual = InterestStorage::get_last_accrual(env, &pool.id);
+        if now <= last_accrual {
+            return 0;
+        }
+
+        let total_deposits = PoolStorage::get_total_deposits(env, &pool.id);
+        let total_borrows = PoolStorage::get_total_borrows(env, &pool.id);
+        let utilization = PoolStorage::calculate_utilization(total_deposits, total_borrows);
+        let mut model = InterestStorage::get_model(env, &pool.id);
+        let borrow_rate = model.calculate_borrow_rate(utilization);
+
+        let index = InterestStorage::get_interest_index(env, &pool.id);
+        let new_index =
+            InterestStorage::calculate_new_index(env, index, borrow_rate, now - last_accrual);
+        if model.on_accrual(utilization, now - last_accrual) {
+            InterestStorage::set_model(env, &pool.id, &model);
+        }
+        let interest = total_borrows
+            .checked_mul(new_index)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow))
+            / index
+            - total_borrows;
+        let reserves = interest * pool.reserve_factor as i128 / MAX_RESERVE_FACTOR as i128;
+
+        if interest > 0 {
+            PoolStorage::set_total_borrows(env, &pool.id, total_borrows + interest);
+            PoolStorage::set_total_deposits(env, &pool.id, total_deposits + interest - reserves);
+            Self::credit_reserves(env, &pool.id, reserves);
+        }
+        InterestStorage::set_interest_index(env, &pool.id, new_index);
+        InterestStorage::set_last_accrual(env, &pool.id, now);
+
+        LendingEvents::interest_accrued(env, pool.id.clone(), interest, new_index, reserves);
+
+        interest
+    }
+
+    /// Check that risk parameters are internally consistent
+    pub fn validate_risk_params(env: &Env, params: &PoolRiskParams) {
+        if params.collateral_factor <= 0
+            || params.collateral_factor > params.liquidation_threshold
+            || params.liquidation_threshold > PRECISION
+            || !(0..PRECISION).contains(&params.liquidation_penalty)
+            || params.reserve_factor > MAX_RESERVE_FACTOR
+        {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+    }
+
+    /// Create a new lending pool with the default interest rate model
+    pub fn create_pool(
+        env: &Env,
+        pool_id: &String,
+        name: String,
+        asset: String,
+        asset_address: Address,
+        params: PoolRiskParams,
+    ) {
+        if PoolStorage::exists(env, pool_id) {
+            panic_with_error!(env, ContractError::AlreadyExists);
+        }
+        Self::validate_risk_params(env, &params);
+
+        let pool = LendingPool {
+            id: pool_id.clone(),
+            name,
+            asset,
+            asset_address,
+            collateral_factor: params.collateral_factor,
+            liquidation_threshold: params.liquidation_threshold,
+            liquidation_penalty: params.liquidation_penalty,
+            reserve_factor: params.reserve_factor,
+            is_active: true,
+            created_at: env.ledger().timestamp(),
+        };
+        PoolStorage::set(env, &pool);
+        PoolStorage::add_to_list(env, pool_id);
+        InterestStorage::set_model(env, pool_id, &InterestRateModel::default());
+        InterestStorage::set_last_accrual(env, pool_id, env.ledger().timestamp());
+
+        LendingEvents::pool_created(
+            env,
+            pool_id.clone(),
+            pool.asset,
+            pool.asset_address,
+            pool.collateral_factor,
+        );
+    }
+
+    /// Replace a pool's risk parameters, emitting `pool_updated` for every
+    /// parameter that changed
+    ///
+    /// Interest is accrued first so the old reserve factor applies up to now.
+    pub fn set_risk_params(env: &Env, pool_id: &String, params: &PoolRiskParams) {
+        let mut pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        Self::validate_risk_params(env, params);
+        Self::accrue_interest(env, &pool);
+
+        let old = pool.clone();
+        pool.collateral_factor = params.collateral_factor;
+        pool.liquidation_threshold = params.liquidation_threshold;
+        pool.liquidation_penalty = params.liquidation_penalty;
+        pool.reserve_factor = params.reserve_factor;
+        PoolStorage::set(env, &pool);
+
+        for (parameter, old_value, new_value) in [
+            (
+                "collateral_factor",
+                old.collateral_factor,
+                pool.collateral_factor,
+            ),
+            (
+                "liquidation_threshold",
+                old.liquidation_threshold,
+                pool.liquidation_threshold,
+            ),
+            (
+                "liquidation_penalty",
+                old.liquidation_penalty,
+                pool.liquidation_penalty,
+            ),
+            (
+                "reserve_factor",
+                old.reserve_factor as i128,
+                pool.reserve_factor as i128,
+            ),
+        ] {
+            if old_value != new_value {
+                LendingEvents::pool_updated(
+                    env,
+                    pool_id.clone(),
+                    String::from_str(env, parameter),
+                    old_value,
+                    new_value,
+                );
+            }
+        }
+    }
+
+    /// Replace a pool's caps, emitting `pool_updated` for every cap that changed
+    pub fn set_pool_caps(env: &Env, pool_id: &String, caps: PoolCaps) {
+        PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if caps.supply_cap < 0 || caps.borrow_cap < 0 || caps.user_borrow_cap < 0 {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+
+        let old = PoolStorage::get_caps(env, pool_id);
+        PoolStorage::set_caps(env, pool_id, &caps);
+
+        for (parameter, old_value, new_value) in [
+            ("supply_cap", old.supply_cap, caps.supply_cap),
+            ("borrow_cap", old.borrow_cap, caps.borrow_cap),
+            ("user_borrow_cap", old.user_borrow_cap, caps.user_borrow_cap),
+        ] {
+            if old_value != new_value {
+                LendingEvents::pool_updated(
+                    env,
+                    pool_id.clone(),
+                    String::from_str(env, parameter),
+                    old_value,
+                    new_value,
+                );
+            }
+        }
+    }
+
+    /// Replace a pool's pause flags (pausers, admin and guardians)
+    pub fn set_paused_functions(env: &Env, caller: &Address, pool_id: &String, paused: u32) {
+        PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        let current = PoolStorage::get_paused(env, pool_id);
+        PauseControl::require_can_update(env, caller, current, paused);
+        PoolStorage::set_paused(env, pool_id, paused);
+
+        LendingEvents::pool_pause_toggled(env, pool_id.clone(), paused, caller.clone());
+    }
+
+    /// Panics if the function is paused contract-wide or for the pool
+    pub fn require_function_active(env: &Env, pool_id: &String, flag: u32) {
+        PauseControl::require_function_active(env, flag);
+        if PoolStorage::get_paused(env, pool_id) & flag != 0 {
+            panic_with_error!(env, ContractError::FunctionPaused);
+        }
+    }
+
+    /// Send accumulated pool reserves to a treasury
+    ///
+    /// Only reserves backed by assets held by the pool can leave; reserves
+    /// still owed by borrowers become withdrawable once repaid.
+    pub fn withdraw_reserves(env: &Env, pool_id: &String, treasury: &Address, amount: i128) {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+
+        let reserves = PoolStorage::get_reserves(env, pool_id);
+        let cash = PoolStorage::get_total_deposits(env, pool_id) + reserves
+            - PoolStorage::get_total_borrows(env, pool_id);
+        if amount > reserves || amount > cash {
+            panic_with_error!(env, ContractError::InsufficientReserves);
+        }
+
+        PoolStorage::set_reserves(env, pool_id, reserves - amount);
+        token::Client::new(env, &pool.asset_address).transfer(
+            &env.current_contract_address(),
+            treasury,
+            &amount,
+        );
+
+        LendingEvents::reserves_withdrawn(env, pool_id.clone(), treasury.clone(), amount);
+    }
+
+    /// Deposit pool assets and mint pool shares
+    ///
+    /// # Returns
+    /// * `i128` - Pool shares minted
+    pub fn deposit(env: &Env, depositor: &Address, pool_id: &String, amount: i128) -> i128 {
+        let pool = Self::load_active_pool(env, pool_id);
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let supply_cap = PoolStorage::get_caps(env, pool_id).supply_cap;
+        if supply_cap > 0 && total_deposits + amount > supply_cap {
+            panic_with_error!(env, ContractError::CapExceeded);
+        }
+        let total_shares = PoolStorage::get_total_shares(env, pool_id);
+        let shares = PoolStorage::amount_to_shares(total_deposits, total_shares, amount);
+        if shares <= 0 {
+            panic_with_error!(env, ContractError::AmountTooSmall);
+        }
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            depositor,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let now = env.ledger().timestamp();
+        let mut position =
+            PositionStorage::get_deposit(env, depositor, pool_id).unwrap_or(DepositPosition {
+                pool_id: pool_id.clone(),
+                depositor: depositor.clone(),
+                amount: 0,
+                shares: 0,
+                index_at_deposit: 0,
+                deposited_at: now,
+            });
+        position.amount += amount;
+        position.shares += shares;
+        position.index_at_deposit = InterestStorage::get_interest_index(env, pool_id);
+        position.deposited_at = now;
+        PositionStorage::set_deposit(env, &position);
+
+        let new_total_deposits = total_deposits + amount;
+        PoolStorage::set_total_deposits(env, pool_id, new_total_deposits);
+        PoolStorage::set_total_shares(env, pool_id, total_shares + shares);
+
+        LendingEvents::deposit(
+            env,
+            pool_id.clone(),
+            depositor.clone(),
+            amount,
+            shares,
+            new_total_deposits,
+        );
+
+        shares
+    }
+
+    /// Withdraw pool assets by burning pool shares
+    ///
+    /// # Returns
+    /// * `i128` - Pool shares burned
+    pub fn withdraw(env: &Env, withdrawer: &Address, pool_id: &String, amount: i128) -> i128 {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+
+        // Round the shares burned up so withdrawals never dilute other depositors
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let total_shares = PoolStorage::get_total_shares(env, pool_id);
+        if total_deposits <= 0 || total_shares <= 0 {
+            panic_with_error!(env, ContractError::InsufficientLiquidity);
+        }
+        let shares = amount
+            .checked_mul(total_shares)
+            .and_then(|scaled| scaled.checked_add(total_deposits - 1))
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow))
+            / total_deposits;
+        Self::burn_shares(env, &pool, withdrawer, shares, amount);
+        shares
+    }
+
+    /// Burn an exact number of pool shares (deposit receipts) for pool assets
+    /// at the current exchange rate
+    ///
+    /// # Returns
+    /// * `i128` - Pool assets withdrawn
+    pub fn redeem(env: &Env, withdrawer: &Address, pool_id: &String, shares: i128) -> i128 {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if shares <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+
+        let amount = PoolStorage::shares_to_amount(
+            PoolStorage::get_total_deposits(env, pool_id),
+            PoolStorage::get_total_shares(env, pool_id),
+            shares,
+        );
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::AmountTooSmall);
+        }
+        Self::burn_shares(env, &pool, withdrawer, shares, amount);
+        amount
+    }
+
+    /// Burn a depositor's shares and pay out the matching pool assets
+    fn burn_shares(
+        env: &Env,
+        pool: &LendingPool,
+        withdrawer: &Address,
+        shares: i128,
+        amount: i128,
+    ) {
+        let pool_id = &pool.id;
+        let mut position = PositionStorage::get_deposit(env, withdrawer, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PositionNotFound));
+
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let total_borrows = PoolStorage::get_total_borrows(env, pool_id);
+        let total_shares = PoolStorage::get_total_shares(env, pool_id);
+        if shares > position.shares {
+            panic_with_error!(env, ContractError::InsufficientBalance);
+        }
+        if amount > PoolStorage::calculate_available_liquidity(total_deposits, total_borrows) {
+            panic_with_error!(env, ContractError::InsufficientLiquidity);
+        }
+
+        position.shares -= shares;
+        if position.shares == 0 {
+            PositionStorage::remove_deposit(env, withdrawer, pool_id);
+        } else {
+            position.amount = (position.amount - amount).max(0);
+            PositionStorage::set_deposit(env, &position);
+        }
+
+        let new_total_deposits = total_deposits - amount;
+        PoolStorage::set_total_deposits(env, pool_id, new_total_deposits);
+        PoolStorage::set_total_shares(env, pool_id, total_shares - shares);
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            &env.current_contract_address(),
+            withdrawer,
+            &amount,
+        );
+
+        LendingEvents::withdraw(
+            env,
+            pool_id.clone(),
+            withdrawer.clone(),
+            amount,
+            shares,
+            new_total_deposits,
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{contractclient, panic_with_error, token, Address, Bytes, Env, String};
+
+use super::config::LendingConfigStorage;
+use super::control::{LendingControl, MAX_RESERVE_FACTOR};
+use super::pool::PoolStorage;
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+
+/// Interface a contract implements to receive flash loans
+///
+/// The callback runs with the borrowed amount already in the receiver's
+/// balance and must send `amount + fee` of `asset` back to the lending
+/// contract before it returns.
+#[contractclient(name = "FlashLoanReceiverClient")]
+pub trait FlashLoanReceiver {
+    fn on_flash_loan(env: Env, asset: Address, amount: i128, fee: i128, params: Bytes);
+}
+
+impl LendingControl {
+    /// Fee charged on a flash loan of `amount`, rounded up
+    pub fn flash_loan_fee(env: &Env, amount: i128) -> i128 {
+        let fee_bps = LendingConfigStorage::get(env).flash_loan_fee_bps as i128;
+        (amount * fee_bps + MAX_RESERVE_FACTOR as i128 - 1) / MAX_RESERVE_FACTOR as i128
+    }
+
+    /// Lend pool liquidity for the duration of a receiver callback
+    ///
+    /// The loan must be returned with its fee before the callback ends,
+    /// otherwise the whole invocation fails. The fee is split between
+    /// suppliers and reserves like interest.
+    ///
+    /// # Returns
+    /// * `i128` - Fee paid
+    pub fn flash_loan(
+        env: &Env,
+        receiver: &Address,
+        pool_id: &String,
+        amount: i128,
+        params: Bytes,
+    ) -> i128 {
+        let pool = Self::load_active_pool(env, pool_id);
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let total_borrows = PoolStorage::get_total_borrows(env, pool_id);
+        if amount > PoolStorage::calculate_available_liquidity(total_deposits, total_borrows) {
+            panic_with_error!(env, ContractError::InsufficientLiquidity);
+        }
+
+        let fee = Self::flash_loan_fee(env, amount);
+        let asset = token::Client::new(env, &pool.asset_address);
+        let balance_before = asset.balance(&env.current_contract_address());
+
+        asset.transfer(&env.current_contract_address(), receiver, &amount);
+        FlashLoanReceiverClient::new(env, receiver).on_flash_loan(
+            &pool.asset_address,
+            &amount,
+            &fee,
+            &params,
+        );
+        if asset.balance(&env.current_contract_address()) < balance_before + fee {
+            panic_with_error!(env, ContractError::FlashLoanNotRepaid);
+        }
+
+        let reserves = fee * pool.reserve_factor as i128 / MAX_RESERVE_FACTOR as i128;
+        PoolStorage::set_total_deposits(env, pool_id, total_deposits + fee - reserves);
+        Self::credit_reserves(env, pool_id, reserves);
+
+        LendingEvents::flash_loan(env, pool_id.clone(), receiver.clone(), amount, fee);
+        fee
+    }
+}
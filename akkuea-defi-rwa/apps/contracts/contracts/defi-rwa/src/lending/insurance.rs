@@ -0,0 +1,70 @@
+use soroban_sdk::{panic_with_error, token, Address, Env, String};
+
+use super::config::LendingConfigStorage;
+use super::control::{LendingControl, MAX_RESERVE_FACTOR};
+use super::pool::PoolStorage;
+use super::receipt::ReceiptToken;
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+
+impl LendingControl {
+    /// Credit a pool's share of interest or fees to reserves and its insurance fund
+    ///
+    /// `insurance_share_bps` of the amount goes to the insurance fund, the
+    /// rest stays in the withdrawable reserves.
+    pub fn credit_reserves(env: &Env, pool_id: &String, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        let share_bps = LendingConfigStorage::get(env).insurance_share_bps as i128;
+        let insured = amount * share_bps / MAX_RESERVE_FACTOR as i128;
+        if insured > 0 {
+            PoolStorage::add_insurance(env, pool_id, insured);
+        }
+        PoolStorage::add_reserves(env, pool_id, amount - insured);
+    }
+
+    /// Add pool assets to a pool's insurance fund
+    pub fn fund_insurance(env: &Env, funder: &Address, pool_id: &String, amount: i128) {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            funder,
+            env.current_contract_address(),
+            &amount,
+        );
+        PoolStorage::add_insurance(env, pool_id, amount);
+
+        LendingEvents::insurance_funded(env, pool_id.clone(), funder.clone(), amount);
+    }
+
+    /// Close the hole left in a pool by written-off debt
+    ///
+    /// The insurance fund pays first. Whatever it cannot cover is taken from
+    /// `total_deposits`, writing down the value of every deposit receipt.
+    pub fn cover_shortfall(env: &Env, pool_id: &String, borrower: &Address, shortfall: i128) {
+        let insurance = PoolStorage::get_insurance(env, pool_id);
+        let covered = insurance.min(shortfall);
+        PoolStorage::set_insurance(env, pool_id, insurance - covered);
+
+        let total_deposits = PoolStorage::get_total_deposits(env, pool_id);
+        let socialized = (shortfall - covered).min(total_deposits);
+        if socialized > 0 {
+            PoolStorage::set_total_deposits(env, pool_id, total_deposits - socialized);
+        }
+
+        LendingEvents::shortfall_covered(
+            env,
+            pool_id.clone(),
+            borrower.clone(),
+            shortfall,
+            covered,
+            socialized,
+            ReceiptToken::exchange_rate(env, pool_id),
+        );
+    }
+}
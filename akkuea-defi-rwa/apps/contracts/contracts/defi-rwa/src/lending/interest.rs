@@ -1,6 +1,7 @@
-use soroban_sdk::{contracttype, Env, String};
+use soroban_sdk::{contracttype, panic_with_error, Env, String};
 
 use super::keys::{lending_bump, LendingKey};
+use crate::errors::ContractError;
 
 /// Precision for fixed-point calculations (18 decimals)
 pub const PRECISION: i128 = 1_000_000_000_000_000_000;
@@ -8,11 +9,15 @@ pub const PRECISION: i128 = 1_000_000_000_000_000_000;
 /// Seconds per year for APY calculations
 pub const SECONDS_PER_YEAR: u64 = 31_536_000;
 
-/// Interest rate model parameters
-/// Uses linear model: rate = base + (utilization * slope)
-#[derive(Clone)]
+/// Longest period compounded in a single step
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Jump rate model parameters
+/// Uses a two-slope linear model that steepens above optimal utilization:
+/// rate = base + (utilization * slope)
+#[derive(Clone, Debug, PartialEq)]
 #[contracttype]
-pub struct InterestRateModel {
+pub struct JumpRateModel {
     /// Base rate (in PRECISION units, e.g., 2% = 0.02 * PRECISION)
     pub base_rate: i128,
     /// Slope below optimal utilization
@@ -23,8 +28,8 @@ pub struct InterestRateModel {
     pub optimal_utilization: i128,
 }
 
-impl Default for InterestRateModel {
-    /// Create default interest rate model
+impl Default for JumpRateModel {
+    /// Create default jump rate model
     /// Base: 2%, Slope1: 4%, Slope2: 75%, Optimal: 80%
     fn default() -> Self {
         Self {
@@ -36,7 +41,7 @@ impl Default for InterestRateModel {
     }
 }
 
-impl InterestRateModel {
+impl JumpRateModel {
     /// Calculate borrow rate based on utilization
     pub fn calculate_borrow_rate(&self, utilization: i128) -> i128 {
         if utilization <= self.optimal_utilization {
@@ -50,6 +55,108 @@ impl InterestRateModel {
             rate_at_optimal + (excess_utilization * self.slope2) / remaining
         }
     }
+}
+
+/// Adaptive model parameters
+/// Steers utilization towards a target by moving the rate paid at the target:
+/// it rises while the pool is busier than the target and falls while it is idler.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AdaptiveRateModel {
+    /// Utilization the model steers towards (PRECISION scaled)
+    pub target_utilization: i128,
+    /// Current borrow rate at the target utilization (PRECISION scaled)
+    pub rate_at_target: i128,
+    /// Lower bound for `rate_at_target`
+    pub min_rate: i128,
+    /// Upper bound for `rate_at_target`
+    pub max_rate: i128,
+    /// Yearly relative change of `rate_at_target` at 0% or 100% utilization
+    pub adjustment_speed: i128,
+}
+
+impl AdaptiveRateModel {
+    /// Distance from the target, normalized to [-PRECISION, PRECISION]
+    fn utilization_error(&self, utilization: i128) -> i128 {
+        if utilization > self.target_utilization {
+            (utilization - self.target_utilization) * PRECISION
+                / (PRECISION - self.target_utilization)
+        } else {
+            (utilization - self.target_utilization) * PRECISION / self.target_utilization
+        }
+    }
+
+    /// Calculate borrow rate based on utilization
+    ///
+    /// Ranges from half the rate at target (idle pool) to 1.5x (fully utilized).
+    pub fn calculate_borrow_rate(&self, utilization: i128) -> i128 {
+        let error = self.utilization_error(utilization);
+        self.rate_at_target + self.rate_at_target * error / (2 * PRECISION)
+    }
+
+    /// Move the rate at target for a period spent at `utilization`
+    pub fn adapt(&mut self, utilization: i128, time_elapsed: u64) {
+        let error = self.utilization_error(utilization);
+        let change = self.rate_at_target * self.adjustment_speed / PRECISION * error / PRECISION
+            * time_elapsed as i128
+            / SECONDS_PER_YEAR as i128;
+        self.rate_at_target = (self.rate_at_target + change).clamp(self.min_rate, self.max_rate);
+    }
+}
+
+/// Interest rate model of a pool
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum InterestRateModel {
+    /// Two-slope model with a kink at optimal utilization
+    JumpRate(JumpRateModel),
+    /// Constant borrow rate (PRECISION scaled)
+    Fixed(i128),
+    /// Utilization-targeting model that adjusts itself over time
+    Adaptive(AdaptiveRateModel),
+}
+
+impl Default for InterestRateModel {
+    /// Create default interest rate model (jump rate with default parameters)
+    fn default() -> Self {
+        InterestRateModel::JumpRate(JumpRateModel::default())
+    }
+}
+
+impl InterestRateModel {
+    /// Check that the model parameters are usable
+    pub fn validate(&self, env: &Env) {
+        let valid = match self {
+            InterestRateModel::JumpRate(model) => {
+                model.base_rate >= 0
+                    && model.slope1 >= 0
+                    && model.slope2 >= 0
+                    && model.optimal_utilization > 0
+                    && model.optimal_utilization < PRECISION
+            }
+            InterestRateModel::Fixed(rate) => *rate >= 0,
+            InterestRateModel::Adaptive(model) => {
+                model.target_utilization > 0
+                    && model.target_utilization < PRECISION
+                    && model.min_rate > 0
+                    && model.min_rate <= model.rate_at_target
+                    && model.rate_at_target <= model.max_rate
+                    && model.adjustment_speed >= 0
+            }
+        };
+        if !valid {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+    }
+
+    /// Calculate borrow rate based on utilization
+    pub fn calculate_borrow_rate(&self, utilization: i128) -> i128 {
+        match self {
+            InterestRateModel::JumpRate(model) => model.calculate_borrow_rate(utilization),
+            InterestRateModel::Fixed(rate) => *rate,
+            InterestRateModel::Adaptive(model) => model.calculate_borrow_rate(utilization),
+        }
+    }
 
     /// Calculate supply rate based on borrow rate and utilization
     pub fn calculate_supply_rate(
@@ -62,6 +169,41 @@ impl InterestRateModel {
         let effective_rate = (borrow_rate * utilization) / PRECISION;
         (effective_rate * (PRECISION - reserve_factor)) / PRECISION
     }
+
+    /// Update stateful models for a period spent at `utilization`
+    ///
+    /// # Returns
+    /// * `bool` - Whether the model changed and needs to be stored
+    pub fn on_accrual(&mut self, utilization: i128, time_elapsed: u64) -> bool {
+        match self {
+            InterestRateModel::Adaptive(model) => {
+                model.adapt(utilization, time_elapsed);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Compound a yearly rate into the equivalent annual yield
+    pub fn annual_yield(env: &Env, rate: i128) -> i128 {
+        InterestStorage::calculate_new_index(env, PRECISION, rate, SECONDS_PER_YEAR) - PRECISION
+    }
+}
+
+/// Current rates of a pool (all PRECISION scaled)
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PoolRates {
+    /// Borrowed share of deposits
+    pub utilization: i128,
+    /// Yearly borrow rate
+    pub borrow_apr: i128,
+    /// Borrow rate compounded per second over a year
+    pub borrow_apy: i128,
+    /// Yearly supply rate, net of reserves
+    pub supply_apr: i128,
+    /// Supply rate compounded per second over a year
+    pub supply_apy: i128,
 }
 
 /// Interest rate storage helpers
@@ -110,7 +252,21 @@ impl InterestStorage {
     }
 
     /// Calculate new interest index based on time elapsed
-    pub fn calculate_new_index(current_index: i128, borrow_rate: i128, time_elapsed: u64) -> i128 {
+    ///
+    /// Per-second compounding is approximated with the first terms of the
+    /// binomial expansion of `(1 + r)^n` over steps of at most one day, where
+    /// those terms are accurate. Whole days are then compounded by squaring,
+    /// so one accrual over a long idle period yields the same index as many
+    /// short accruals.
+    ///
+    /// # Panics
+    /// Panics with `MathOverflow` if the index no longer fits in an i128
+    pub fn calculate_new_index(
+        env: &Env,
+        current_index: i128,
+        borrow_rate: i128,
+        time_elapsed: u64,
+    ) -> i128 {
         if time_elapsed == 0 {
             return current_index;
         }
@@ -118,8 +274,49 @@ impl InterestStorage {
         // Calculate rate per second
         let rate_per_second = borrow_rate / (SECONDS_PER_YEAR as i128);
 
-        // Calculate accumulated interest: index * (1 + rate * time)
-        let interest_factor = PRECISION + (rate_per_second * (time_elapsed as i128));
-        (current_index * interest_factor) / PRECISION
+        let mut factor = Self::step_factor(rate_per_second, time_elapsed % SECONDS_PER_DAY);
+        let mut day_factor = Self::step_factor(rate_per_second, SECONDS_PER_DAY);
+        let mut days = time_elapsed / SECONDS_PER_DAY;
+        while days > 0 {
+            if days & 1 == 1 {
+                factor = Self::mul_precision(env, factor, day_factor);
+            }
+            days >>= 1;
+            if days > 0 {
+                day_factor = Self::mul_precision(env, day_factor, day_factor);
+            }
+        }
+
+        Self::mul_precision(env, current_index, factor)
+    }
+
+    /// Growth factor `(1 + r)^n` for a step of at most one day
+    fn step_factor(rate_per_second: i128, seconds: u64) -> i128 {
+        let n = seconds as i128;
+
+        // (1 + r)^n ~= 1 + n*r + n(n-1)/2 * r^2 + n(n-1)(n-2)/6 * r^3
+        // Each term derives from the previous one so small rates keep their precision
+        let first_term = rate_per_second * n;
+        let second_term = if n > 1 {
+            first_term * (rate_per_second * (n - 1)) / PRECISION / 2
+        } else {
+            0
+        };
+        let third_term = if n > 2 {
+            second_term * (rate_per_second * (n - 2)) / PRECISION / 3
+        } else {
+            0
+        };
+        PRECISION + first_term + second_term + third_term
+    }
+
+    /// `a * b / PRECISION` for non-negative values, split to avoid
+    /// intermediate overflow on large operands
+    fn mul_precision(env: &Env, a: i128, b: i128) -> i128 {
+        let (high, low) = (a / PRECISION, a % PRECISION);
+        high.checked_mul(b)
+            .and_then(|value| value.checked_add(low.checked_mul(b / PRECISION)?))
+            .and_then(|value| value.checked_add(low * (b % PRECISION) / PRECISION))
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow))
     }
 }
@@ -36,6 +36,10 @@ pub enum LendingKey {
     /// Storage: Persistent
     PoolTotalDeposits(String),
 
+    /// Pool's total deposit shares
+    /// Storage: Persistent
+    PoolTotalShares(String),
+
     /// Pool's total borrows
     /// Storage: Persistent
     PoolTotalBorrows(String),
@@ -52,15 +56,35 @@ pub enum LendingKey {
     /// Storage: Persistent
     PoolReserves(String),
 
+    /// Insurance fund balance for pool
+    /// Storage: Persistent
+    PoolInsurance(String),
+
+    /// Debt written off after liquidations ran out of collateral
+    /// Storage: Persistent
+    PoolBadDebt(String),
+
+    /// Supply, borrow and per-user borrow caps of a pool
+    /// Storage: Persistent
+    PoolCaps(String),
+
     /// Interest rate model parameters
     /// Storage: Instance
     InterestRateModel(String),
 
+    /// Deposit receipt allowance (pool, owner, spender)
+    /// Storage: Temporary
+    ReceiptAllowance(String, Address, Address),
+
     /// Global lending configuration
     /// Storage: Instance
     LendingConfig,
 
-    /// Pool pause status
+    /// Whether a token is accepted as collateral
+    /// Storage: Instance
+    CollateralToken(Address),
+
+    /// Pool pause flags
     /// Storage: Instance
     PoolPaused(String),
 }
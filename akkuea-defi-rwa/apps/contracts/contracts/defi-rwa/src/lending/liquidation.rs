@@ -0,0 +1,169 @@
+use soroban_sdk::{panic_with_error, token, Address, Env, String};
+
+use super::config::LendingConfigStorage;
+use super::control::LendingControl;
+use super::interest::PRECISION;
+use super::pool::PoolStorage;
+use super::positions::{BorrowPosition, PositionStorage};
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+use crate::oracle::PriceOracle;
+
+impl LendingControl {
+    /// Repay part of an unhealthy account's debt in exchange for its collateral
+    ///
+    /// The liquidator repays at most `close_factor` of the debt owed to
+    /// `pool_id` and seizes collateral posted in `collateral_pool_id` (which
+    /// may be the same pool) worth the repaid amount plus that pool's
+    /// liquidation penalty. Once the account has no collateral left, its
+    /// remaining debt is written off, recorded as bad debt and covered by the
+    /// insurance fund and, failing that, by suppliers.
+    ///
+    /// # Returns
+    /// * `i128` - Collateral seized
+    pub fn liquidate(
+        env: &Env,
+        liquidator: &Address,
+        borrower: &Address,
+        pool_id: &String,
+        collateral_pool_id: &String,
+        amount: i128,
+    ) -> i128 {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        let collateral_pool = PoolStorage::get(env, collateral_pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        Self::accrue_interest(env, &pool);
+        Self::accrue_interest(env, &collateral_pool);
+        let mut position = PositionStorage::get_borrow(env, borrower, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PositionNotFound));
+
+        Self::settle_interest(env, &pool, &mut position);
+        if position.principal == 0 {
+            panic_with_error!(env, ContractError::NoOutstandingDebt);
+        }
+        if Self::account_health(env, borrower) >= PRECISION {
+            panic_with_error!(env, ContractError::PositionHealthy);
+        }
+
+        let same_pool = pool_id == collateral_pool_id;
+        let mut collateral_position = if same_pool {
+            position.clone()
+        } else {
+            PositionStorage::get_borrow(env, borrower, collateral_pool_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::PositionNotFound))
+        };
+        if collateral_position.collateral_amount == 0 {
+            panic_with_error!(env, ContractError::PositionNotFound);
+        }
+
+        let close_factor = LendingConfigStorage::get(env).close_factor;
+        let mut repaid = amount.min(position.principal * close_factor / PRECISION);
+
+        // Collateral owed for the repaid debt, including the penalty
+        let collateral = collateral_position.collateral();
+        let asset_price = PriceOracle::asset_price(env, &pool.asset_address);
+        let unit_price = collateral.unit_price(env);
+        let penalty_factor = PRECISION + collateral_pool.liquidation_penalty;
+        let mut seized = repaid * asset_price / PRECISION * penalty_factor / unit_price;
+        if seized > collateral_position.collateral_amount {
+            seized = collateral_position.collateral_amount;
+            let collateral_value = collateral.value(env, seized);
+            repaid = collateral_value * PRECISION / penalty_factor * PRECISION / asset_price;
+        }
+        if repaid <= 0 {
+            panic_with_error!(env, ContractError::AmountTooSmall);
+        }
+        let penalty = seized - seized * PRECISION / penalty_factor;
+
+        token::Client::new(env, &pool.asset_address).transfer(
+            liquidator,
+            env.current_contract_address(),
+            &repaid,
+        );
+
+        position.principal -= repaid;
+        collateral_position.collateral_amount -= seized;
+        let total_borrows = PoolStorage::get_total_borrows(env, pool_id) - repaid;
+        PoolStorage::set_total_borrows(env, pool_id, total_borrows.max(0));
+
+        if same_pool {
+            position.collateral_amount = collateral_position.collateral_amount;
+        } else {
+            Self::store_position(env, &collateral_position);
+        }
+        Self::store_position(env, &position);
+
+        collateral.release(env, liquidator, seized);
+
+        LendingEvents::liquidation(
+            env,
+            pool_id.clone(),
+            borrower.clone(),
+            liquidator.clone(),
+            repaid,
+            seized,
+            penalty,
+        );
+
+        if !Self::has_collateral(env, borrower) {
+            Self::write_off_debt(env, borrower);
+        }
+
+        seized
+    }
+
+    /// Save a borrow position, closing it once it holds neither debt nor collateral
+    fn store_position(env: &Env, position: &BorrowPosition) {
+        if position.principal == 0 && position.collateral_amount == 0 {
+            PositionStorage::remove_borrow(env, &position.borrower, &position.pool_id);
+        } else {
+            PositionStorage::set_borrow(env, position);
+        }
+    }
+
+    /// Check whether an account still has collateral posted in any pool
+    fn has_collateral(env: &Env, borrower: &Address) -> bool {
+        PositionStorage::get_user_borrows(env, borrower)
+            .iter()
+            .any(|pool_id| {
+                PositionStorage::get_borrow(env, borrower, &pool_id)
+                    .map(|position| position.collateral_amount > 0)
+                    .unwrap_or(false)
+            })
+    }
+
+    /// Write off every remaining debt of an account as bad debt
+    fn write_off_debt(env: &Env, borrower: &Address) {
+        for pool_id in PositionStorage::get_user_borrows(env, borrower).iter() {
+            let Some(mut position) = PositionStorage::get_borrow(env, borrower, &pool_id) else {
+                continue;
+            };
+            let pool = PoolStorage::get(env, &pool_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+            Self::accrue_interest(env, &pool);
+            Self::settle_interest(env, &pool, &mut position);
+
+            if position.principal > 0 {
+                let total_borrows = PoolStorage::get_total_borrows(env, &pool_id);
+                PoolStorage::set_total_borrows(
+                    env,
+                    &pool_id,
+                    (total_borrows - position.principal).max(0),
+                );
+                PoolStorage::add_bad_debt(env, &pool_id, position.principal);
+                LendingEvents::bad_debt_recorded(
+                    env,
+                    pool_id.clone(),
+                    borrower.clone(),
+                    position.principal,
+                );
+                Self::cover_shortfall(env, &pool_id, borrower, position.principal);
+            }
+            PositionStorage::remove_borrow(env, borrower, &pool_id);
+        }
+    }
+}
@@ -2,12 +2,31 @@
 // This module provides storage structures and helpers for the lending pool functionality.
 // This module is used to store the lending pool configuration, deposit positions, borrow positions, and interest rate model.
 
+mod account;
+mod borrowing;
+mod collateral;
+mod config;
+mod control;
+mod flash_loan;
+mod insurance;
 mod interest;
 mod keys;
+mod liquidation;
 mod pool;
 mod positions;
+mod rates;
+mod receipt;
 
-pub use interest::{InterestRateModel, InterestStorage, PRECISION, SECONDS_PER_YEAR};
+pub use account::AccountSummary;
+pub use collateral::{Collateral, CollateralStorage};
+pub use config::{LendingConfig, LendingConfigStorage};
+pub use control::{LendingControl, MAX_RESERVE_FACTOR};
+pub use flash_loan::{FlashLoanReceiver, FlashLoanReceiverClient};
+pub use interest::{
+    AdaptiveRateModel, InterestRateModel, InterestStorage, JumpRateModel, PoolRates, PRECISION,
+    SECONDS_PER_YEAR,
+};
 pub use keys::{lending_bump, LendingKey};
-pub use pool::{LendingPool, PoolStorage};
+pub use pool::{LendingPool, PoolCaps, PoolRiskParams, PoolStorage};
 pub use positions::{BorrowPosition, DepositPosition, PositionStorage};
+pub use receipt::ReceiptToken;
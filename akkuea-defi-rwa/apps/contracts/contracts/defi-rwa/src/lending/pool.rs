@@ -29,6 +29,32 @@ pub struct LendingPool {
     pub created_at: u64,
 }
 
+/// Risk parameters chosen when a pool is created
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PoolRiskParams {
+    /// Collateral factor (PRECISION scaled)
+    pub collateral_factor: i128,
+    /// Liquidation threshold (PRECISION scaled)
+    pub liquidation_threshold: i128,
+    /// Liquidation penalty (PRECISION scaled)
+    pub liquidation_penalty: i128,
+    /// Reserve factor in basis points
+    pub reserve_factor: u32,
+}
+
+/// Size limits of a pool (asset units, 0 = no cap)
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct PoolCaps {
+    /// Maximum total deposits
+    pub supply_cap: i128,
+    /// Maximum total borrows
+    pub borrow_cap: i128,
+    /// Maximum debt of a single borrower
+    pub user_borrow_cap: i128,
+}
+
 /// Pool storage helpers
 pub struct PoolStorage;
 
@@ -65,6 +91,23 @@ impl PoolStorage {
         env.storage().persistent().has(&key)
     }
 
+    /// Get pool caps
+    pub fn get_caps(env: &Env, pool_id: &String) -> PoolCaps {
+        let key = LendingKey::PoolCaps(pool_id.clone());
+        env.storage().persistent().get(&key).unwrap_or_default()
+    }
+
+    /// Set pool caps
+    pub fn set_caps(env: &Env, pool_id: &String, caps: &PoolCaps) {
+        let key = LendingKey::PoolCaps(pool_id.clone());
+        env.storage().persistent().set(&key, caps);
+        env.storage().persistent().extend_ttl(
+            &key,
+            lending_bump::PERSISTENT_BUMP,
+            lending_bump::PERSISTENT_BUMP,
+        );
+    }
+
     /// Get total deposits for pool
     pub fn get_total_deposits(env: &Env, pool_id: &String) -> i128 {
         let key = LendingKey::PoolTotalDeposits(pool_id.clone());
@@ -82,6 +125,39 @@ impl PoolStorage {
         );
     }
 
+    /// Get total deposit shares for pool
+    pub fn get_total_shares(env: &Env, pool_id: &String) -> i128 {
+        let key = LendingKey::PoolTotalShares(pool_id.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Set total deposit shares for pool
+    pub fn set_total_shares(env: &Env, pool_id: &String, shares: i128) {
+        let key = LendingKey::PoolTotalShares(pool_id.clone());
+        env.storage().persistent().set(&key, &shares);
+        env.storage().persistent().extend_ttl(
+            &key,
+            lending_bump::PERSISTENT_BUMP,
+            lending_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Convert an underlying amount to pool shares, rounding down
+    pub fn amount_to_shares(total_deposits: i128, total_shares: i128, amount: i128) -> i128 {
+        if total_shares == 0 || total_deposits == 0 {
+            return amount;
+        }
+        amount * total_shares / total_deposits
+    }
+
+    /// Convert pool shares to an underlying amount, rounding down
+    pub fn shares_to_amount(total_deposits: i128, total_shares: i128, shares: i128) -> i128 {
+        if total_shares == 0 {
+            return 0;
+        }
+        shares * total_deposits / total_shares
+    }
+
     /// Get total borrows for pool
     pub fn get_total_borrows(env: &Env, pool_id: &String) -> i128 {
         let key = LendingKey::PoolTotalBorrows(pool_id.clone());
@@ -108,8 +184,59 @@ impl PoolStorage {
     /// Add to pool reserves
     pub fn add_reserves(env: &Env, pool_id: &String, amount: i128) {
         let current = Self::get_reserves(env, pool_id);
+        Self::set_reserves(env, pool_id, current + amount);
+    }
+
+    /// Set pool reserves
+    pub fn set_reserves(env: &Env, pool_id: &String, amount: i128) {
         let key = LendingKey::PoolReserves(pool_id.clone());
+        env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().extend_ttl(
+            &key,
+            lending_bump::PERSISTENT_BUMP,
+            lending_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get pool insurance fund balance
+    pub fn get_insurance(env: &Env, pool_id: &String) -> i128 {
+        let key = LendingKey::PoolInsurance(pool_id.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Add to pool insurance fund
+    pub fn add_insurance(env: &Env, pool_id: &String, amount: i128) {
+        let current = Self::get_insurance(env, pool_id);
+        Self::set_insurance(env, pool_id, current + amount);
+    }
+
+    /// Set pool insurance fund balance
+    pub fn set_insurance(env: &Env, pool_id: &String, amount: i128) {
+        let key = LendingKey::PoolInsurance(pool_id.clone());
+        env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().extend_ttl(
+            &key,
+            lending_bump::PERSISTENT_BUMP,
+            lending_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get bad debt recorded for pool
+    pub fn get_bad_debt(env: &Env, pool_id: &String) -> i128 {
+        let key = LendingKey::PoolBadDebt(pool_id.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Add to pool bad debt
+    pub fn add_bad_debt(env: &Env, pool_id: &String, amount: i128) {
+        let current = Self::get_bad_debt(env, pool_id);
+        let key = LendingKey::PoolBadDebt(pool_id.clone());
         env.storage().persistent().set(&key, &(current + amount));
+        env.storage().persistent().extend_ttl(
+            &key,
+            lending_bump::PERSISTENT_BUMP,
+            lending_bump::PERSISTENT_BUMP,
+        );
     }
 
     /// Calculate utilization rate
@@ -145,14 +272,14 @@ impl PoolStorage {
         env.storage().persistent().set(&key, &list);
     }
 
-    /// Check if pool is paused
-    pub fn is_paused(env: &Env, pool_id: &String) -> bool {
+    /// Get the pool's pause flags
+    pub fn get_paused(env: &Env, pool_id: &String) -> u32 {
         let key = LendingKey::PoolPaused(pool_id.clone());
-        env.storage().instance().get(&key).unwrap_or(false)
+        env.storage().instance().get(&key).unwrap_or(0)
     }
 
-    /// Set pool pause status
-    pub fn set_paused(env: &Env, pool_id: &String, paused: bool) {
+    /// Set the pool's pause flags
+    pub fn set_paused(env: &Env, pool_id: &String, paused: u32) {
         let key = LendingKey::PoolPaused(pool_id.clone());
         env.storage().instance().set(&key, &paused);
     }
@@ -1,5 +1,6 @@
 use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
+use super::collateral::Collateral;
 use super::interest::{InterestStorage, PRECISION};
 use super::keys::{lending_bump, LendingKey};
 
@@ -37,10 +38,25 @@ pub struct BorrowPosition {
     pub collateral_amount: i128,
     /// Collateral asset address
     pub collateral_asset: Address,
+    /// Property whose shares are the collateral, if any
+    pub collateral_property: Option<u64>,
+    /// Pool whose deposit receipts are the collateral, if any
+    pub collateral_pool: Option<String>,
     /// Timestamp of borrow
     pub borrowed_at: u64,
 }
 
+impl BorrowPosition {
+    /// Collateral backing this position
+    pub fn collateral(&self) -> Collateral {
+        match (self.collateral_property, &self.collateral_pool) {
+            (Some(property_id), _) => Collateral::PropertyShares(property_id),
+            (None, Some(pool_id)) => Collateral::PoolReceipts(pool_id.clone()),
+            (None, None) => Collateral::Token(self.collateral_asset.clone()),
+        }
+    }
+}
+
 /// Position storage helpers
 pub struct PositionStorage;
 
@@ -237,6 +253,8 @@ impl PositionStorage {
     }
 
     /// Calculate health factor for position
+    ///
+    /// A position is healthy while the result is at least `PRECISION` (1.0).
     pub fn calculate_health_factor(
         collateral_value: i128,
         debt_value: i128,
@@ -246,7 +264,7 @@ impl PositionStorage {
             return i128::MAX;
         }
 
-        // health = (collateral * liquidation_threshold) / debt
-        (collateral_value * liquidation_threshold) / (debt_value * PRECISION)
+        // health = (collateral * liquidation_threshold) / debt, PRECISION scaled
+        (collateral_value * liquidation_threshold) / debt_value
     }
 }
@@ -0,0 +1,45 @@
+use soroban_sdk::{panic_with_error, Env, String};
+
+use super::control::{LendingControl, MAX_RESERVE_FACTOR};
+use super::interest::{InterestRateModel, InterestStorage, PoolRates, PRECISION};
+use super::pool::PoolStorage;
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+
+impl LendingControl {
+    /// Switch a pool to a new interest rate model
+    ///
+    /// Interest is accrued under the old model up to the switch.
+    pub fn set_rate_model(env: &Env, pool_id: &String, model: &InterestRateModel) {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        model.validate(env);
+
+        Self::accrue_interest(env, &pool);
+        InterestStorage::set_model(env, pool_id, model);
+
+        LendingEvents::rate_model_changed(env, pool_id.clone());
+    }
+
+    /// Current utilization and borrow/supply rates of a pool
+    pub fn current_rates(env: &Env, pool_id: &String) -> PoolRates {
+        let pool = PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        let utilization = PoolStorage::calculate_utilization(
+            PoolStorage::get_total_deposits(env, pool_id),
+            PoolStorage::get_total_borrows(env, pool_id),
+        );
+        let model = InterestStorage::get_model(env, pool_id);
+        let borrow_apr = model.calculate_borrow_rate(utilization);
+        let reserve_factor = pool.reserve_factor as i128 * PRECISION / MAX_RESERVE_FACTOR as i128;
+        let supply_apr = model.calculate_supply_rate(borrow_apr, utilization, reserve_factor);
+
+        PoolRates {
+            utilization,
+            borrow_apr,
+            borrow_apy: InterestRateModel::annual_yield(env, borrow_apr),
+            supply_apr,
+            supply_apy: InterestRateModel::annual_yield(env, supply_apr),
+        }
+    }
+}
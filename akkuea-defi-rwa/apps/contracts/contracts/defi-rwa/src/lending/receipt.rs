@@ -0,0 +1,127 @@
+use soroban_sdk::{panic_with_error, Address, Env, String};
+
+use super::interest::{InterestStorage, PRECISION};
+use super::keys::LendingKey;
+use super::pool::PoolStorage;
+use super::positions::{DepositPosition, PositionStorage};
+use crate::errors::ContractError;
+use crate::events::LendingEvents;
+use crate::storage::shares;
+
+/// Deposit receipt token helpers
+///
+/// Every pool issues receipts, which are the pool shares recorded in
+/// `DepositPosition`. They are exposed through a SEP-41 style interface scoped
+/// by pool ID. A receipt is worth `total_deposits / total_shares` of the pool
+/// asset, so its value grows as interest accrues to suppliers.
+pub struct ReceiptToken;
+
+impl ReceiptToken {
+    /// Pool assets per receipt (PRECISION scaled)
+    pub fn exchange_rate(env: &Env, pool_id: &String) -> i128 {
+        let total_shares = PoolStorage::get_total_shares(env, pool_id);
+        if total_shares == 0 {
+            return PRECISION;
+        }
+        PoolStorage::get_total_deposits(env, pool_id) * PRECISION / total_shares
+    }
+
+    /// Receipt balance of an account
+    pub fn balance(env: &Env, pool_id: &String, owner: &Address) -> i128 {
+        PositionStorage::get_deposit(env, owner, pool_id)
+            .map(|position| position.shares)
+            .unwrap_or(0)
+    }
+
+    /// Move receipts between two accounts and emit the transfer event
+    ///
+    /// The deposited amount recorded for the sender moves pro rata with the
+    /// receipts.
+    pub fn transfer(env: &Env, pool_id: &String, from: &Address, to: &Address, amount: i128) {
+        PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount < 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        let mut sender = PositionStorage::get_deposit(env, from, pool_id)
+            .filter(|position| position.shares >= amount)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::InsufficientBalance));
+
+        if from != to && amount > 0 {
+            let moved_amount = sender.amount * amount / sender.shares;
+            let now = env.ledger().timestamp();
+            let mut recipient =
+                PositionStorage::get_deposit(env, to, pool_id).unwrap_or(DepositPosition {
+                    pool_id: pool_id.clone(),
+                    depositor: to.clone(),
+                    amount: 0,
+                    shares: 0,
+                    index_at_deposit: InterestStorage::get_interest_index(env, pool_id),
+                    deposited_at: now,
+                });
+
+            sender.shares -= amount;
+            sender.amount -= moved_amount;
+            if sender.shares == 0 {
+                PositionStorage::remove_deposit(env, from, pool_id);
+            } else {
+                PositionStorage::set_deposit(env, &sender);
+            }
+            recipient.shares += amount;
+            recipient.amount += moved_amount;
+            PositionStorage::set_deposit(env, &recipient);
+        }
+
+        LendingEvents::receipt_transfer(env, pool_id.clone(), from.clone(), to.clone(), amount);
+    }
+
+    /// Transfer receipts on behalf of `from` using the spender's allowance
+    pub fn transfer_from(
+        env: &Env,
+        pool_id: &String,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        amount: i128,
+    ) {
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        let key = LendingKey::ReceiptAllowance(pool_id.clone(), from.clone(), spender.clone());
+        shares::consume_allowance(env, &key, amount);
+        Self::transfer(env, pool_id, from, to, amount);
+    }
+
+    /// Active allowance of a spender (0 if none or expired)
+    pub fn allowance(env: &Env, pool_id: &String, owner: &Address, spender: &Address) -> i128 {
+        let key = LendingKey::ReceiptAllowance(pool_id.clone(), owner.clone(), spender.clone());
+        shares::read_allowance(env, &key)
+    }
+
+    /// Approve a spender and emit the approval event
+    pub fn approve(
+        env: &Env,
+        pool_id: &String,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        PoolStorage::get(env, pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        if amount < 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        let key = LendingKey::ReceiptAllowance(pool_id.clone(), from.clone(), spender.clone());
+        shares::write_allowance(env, &key, amount, expiration_ledger);
+
+        LendingEvents::receipt_approval(
+            env,
+            pool_id.clone(),
+            from.clone(),
+            spender.clone(),
+            amount,
+            expiration_ledger,
+        );
+    }
+}
@@ -5,17 +5,49 @@
 //! It provides functionality for property registration, share management, and ownership tracking.
 
 mod access;
+mod buyout;
+mod compliance;
+mod dividends;
+mod errors;
 mod events;
+mod governance;
 mod lending;
+mod market;
+mod offering;
+mod oracle;
+mod registry;
 mod storage;
+mod timelock;
+mod token;
+mod upgrade;
 
 pub use access::*;
+pub use buyout::*;
+pub use compliance::*;
+pub use dividends::*;
+pub use errors::*;
 pub use events::*;
+pub use governance::*;
 pub use lending::*;
+pub use market::*;
+pub use offering::*;
+pub use oracle::*;
+pub use registry::*;
 pub use storage::*;
+pub use timelock::*;
+pub use token::*;
+pub use upgrade::*;
 
 // Import necessary types for the contract (always available, not just in tests)
-use soroban_sdk::{contract, contractimpl, vec, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, String, Vec,
+};
+
+use storage::config::{increment_property_counter, TokenConfig};
+use storage::property::PropertyMetadata;
+
+/// Shares are whole units of ownership and cannot be split
+pub const SHARE_DECIMALS: u32 = 0;
 
 #[cfg(test)]
 mod test;
@@ -25,8 +57,1151 @@ pub struct PropertyTokenContract;
 
 #[contractimpl]
 impl PropertyTokenContract {
-    /// Placeholder function - will be replaced with actual contract logic
-    pub fn hello(env: Env, to: String) -> Vec<String> {
-        vec![&env, String::from_str(&env, "Hello"), to]
+    // =========================================================================
+    // Administration
+    // =========================================================================
+
+    /// Initializes the contract admin, the share token configuration and the
+    /// minimum timelock delay (in seconds, must be non-zero)
+    pub fn initialize(env: Env, admin: Address, name: String, symbol: String, min_delay: u64) {
+        admin.require_auth();
+        AdminControl::initialize(&env, &admin);
+        UpgradeControl::initialize(&env);
+        TimelockControl::initialize(&env, min_delay);
+        TokenConfig::new(symbol, name, SHARE_DECIMALS, admin).save(&env);
+    }
+
+    /// Revokes a role from an account (admin only)
+    pub fn revoke_role(env: Env, caller: Address, account: Address, role: Role) {
+        caller.require_auth();
+        AdminControl::require_admin(&env, &caller);
+        if role == Role::Admin {
+            panic_with_error!(env, ContractError::InvalidParameters)
+        }
+        RoleStorage::revoke_role(&env, &account, &role);
+    }
+
+    /// Returns whether an account holds a role
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        RoleStorage::has_role(&env, &account, &role)
+    }
+
+    /// Accepts a pending admin transfer (nominated through the timelock)
+    pub fn accept_admin(env: Env, new_admin: Address) {
+        new_admin.require_auth();
+        AdminControl::transfer_admin_accept(&env, &new_admin);
+    }
+
+    /// Returns the current admin
+    pub fn get_admin(env: Env) -> Option<Address> {
+        AdminControl::get_admin(&env)
+    }
+
+    /// Returns the admin nominated through the timelock, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        AdminControl::get_pending_admin(&env)
+    }
+
+    /// Pauses the contract (admin, pauser or guardian)
+    pub fn pause(env: Env, caller: Address) {
+        caller.require_auth();
+        PauseControl::pause(&env, &caller);
+    }
+
+    /// Unpauses the contract (admin or pauser)
+    ///
+    /// Not allowed until stored records are migrated to the current schema.
+    pub fn unpause(env: Env, caller: Address) {
+        caller.require_auth();
+        UpgradeControl::require_migrated(&env);
+        PauseControl::unpause(&env, &caller);
+    }
+
+    /// Pauses individual functions contract-wide with a mask of `PAUSE_*` flags
+    ///
+    /// Admin and pausers can set or clear flags; guardians can only add them.
+    pub fn set_paused_functions(env: Env, caller: Address, paused: u32) {
+        caller.require_auth();
+        PauseControl::set_paused_functions(&env, &caller, paused);
+    }
+
+    /// Returns the functions paused contract-wide
+    pub fn get_paused_functions(env: Env) -> u32 {
+        PauseControl::paused_functions(&env)
+    }
+
+    // =========================================================================
+    // Timelock
+    // =========================================================================
+
+    /// Queues a pool risk parameter or cap change, collateral whitelist change,
+    /// rate model swap, lending/market/oracle configuration change, role grant,
+    /// admin transfer or delay change (admin only)
+    ///
+    /// # Returns
+    /// * `u64` - ID of the queued operation
+    pub fn queue_operation(env: Env, admin: Address, operation: TimelockOperation) -> u64 {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        TimelockControl::queue(&env, operation)
+    }
+
+    /// Applies a queued operation once its delay has passed (admin only)
+    pub fn execute_operation(env: Env, admin: Address, operation_id: u64) {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        TimelockControl::execute(&env, operation_id);
+    }
+
+    /// Drops a queued operation (admin or guardian, also while paused)
+    pub fn cancel_operation(env: Env, caller: Address, operation_id: u64) {
+        caller.require_auth();
+        required_admin_or_role(&env, &caller, &Role::Guardian);
+        TimelockControl::cancel(&env, &caller, operation_id);
+    }
+
+    /// Returns a queued operation
+    pub fn get_queued_operation(env: Env, operation_id: u64) -> Option<QueuedOperation> {
+        TimelockStorage::get_operation(&env, operation_id)
+    }
+
+    /// Returns the delay applied to each kind of operation
+    pub fn get_timelock_config(env: Env) -> TimelockConfig {
+        TimelockStorage::get_config(&env)
+    }
+
+    // =========================================================================
+    // Upgrades
+    // =========================================================================
+
+    /// Replaces the contract WASM (admin only, contract must be paused)
+    ///
+    /// Run `migrate` afterwards if the new build bumps the storage schema.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        AdminControl::require_admin(&env, &admin);
+        UpgradeControl::upgrade(&env, &new_wasm_hash);
+    }
+
+    /// Migrates up to `max_items` properties and pools to the current storage
+    /// schema (admin only, contract must be paused)
+    ///
+    /// # Returns
+    /// * `MigrationStatus` - Progress after the batch
+    pub fn migrate(env: Env, admin: Address, max_items: u32) -> MigrationStatus {
+        admin.require_auth();
+        AdminControl::require_admin(&env, &admin);
+        UpgradeControl::migrate(&env, max_items)
+    }
+
+    /// Migrates the lending positions of a batch of accounts
+    /// (admin only, contract must be paused)
+    ///
+    /// # Returns
+    /// * `u32` - Number of positions rewritten
+    pub fn migrate_positions(env: Env, admin: Address, accounts: Vec<Address>) -> u32 {
+        admin.require_auth();
+        AdminControl::require_admin(&env, &admin);
+        UpgradeControl::migrate_positions(&env, &accounts)
+    }
+
+    /// Returns the storage schema version of the stored records
+    pub fn get_schema_version(env: Env) -> u32 {
+        MigrationStorage::get_schema_version(&env)
+    }
+
+    /// Returns the progress of the running migration
+    pub fn get_migration_status(env: Env) -> MigrationStatus {
+        MigrationStorage::get_status(&env)
+    }
+
+    // =========================================================================
+    // Property Registry
+    // =========================================================================
+
+    /// Registers a new property and mints all of its shares to the owner
+    /// (admin or verifier, with the owner's consent)
+    ///
+    /// # Returns
+    /// * `u64` - ID of the newly registered property
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_property(
+        env: Env,
+        registrar: Address,
+        owner: Address,
+        name: String,
+        description: String,
+        location: String,
+        valuation: i128,
+        total_shares: u64,
+    ) -> u64 {
+        registrar.require_auth();
+        if owner != registrar {
+            owner.require_auth();
+        }
+        require_active_and_authorized(&env, &registrar, Some(&Role::Verifier));
+        if !TokenConfig::is_initialized(&env) {
+            panic_with_error!(env, ContractError::NotInitialized);
+        }
+        if valuation <= 0 {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+        if total_shares == 0 {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+
+        let property_id = increment_property_counter(&env);
+        let metadata = PropertyMetadata::new(
+            property_id,
+            owner.clone(),
+            name.clone(),
+            description,
+            location,
+            valuation,
+            total_shares,
+            env.ledger().timestamp(),
+        );
+        metadata.save(&env);
+        RegistryControl::record_initial_valuation(&env, &metadata);
+
+        shares::set_total_shares(&env, property_id, total_shares);
+        shares::increase_balance(&env, property_id, &owner, total_shares);
+
+        PropertyEvents::property_registered(
+            &env,
+            property_id_to_string(&env, property_id),
+            owner,
+            name,
+            total_shares as i128,
+            valuation / total_shares as i128,
+        );
+
+        property_id
+    }
+
+    /// Marks a property as verified (admin or verifier)
+    pub fn verify_property(env: Env, verifier: Address, property_id: u64) {
+        verifier.require_auth();
+        require_active_and_authorized(&env, &verifier, Some(&Role::Verifier));
+
+        let mut metadata = PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        if metadata.is_verified {
+            panic_with_error!(env, ContractError::PropertyAlreadyVerified);
+        }
+        metadata.set_verified(&env);
+
+        PropertyEvents::property_verified(&env, property_id_to_string(&env, property_id), verifier);
+    }
+
+    /// Deactivates a property so its shares can no longer be traded (admin only)
+    pub fn deactivate_property(env: Env, caller: Address, property_id: u64) {
+        caller.require_auth();
+        require_active_and_authorized(&env, &caller, None);
+
+        let mut metadata = PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        metadata.set_active(&env, false);
+    }
+
+    /// Returns the metadata of a registered property
+    pub fn get_property(env: Env, property_id: u64) -> PropertyMetadata {
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound))
+    }
+
+    // =========================================================================
+    // Documents and Valuations
+    // =========================================================================
+
+    /// Attaches a document to a property (owner, admin or verifier)
+    pub fn add_document(
+        env: Env,
+        issuer: Address,
+        property_id: u64,
+        document_type: DocumentType,
+        content_hash: BytesN<32>,
+    ) {
+        issuer.require_auth();
+        PauseControl::require_not_paused(&env);
+        RegistryControl::add_document(&env, &issuer, property_id, document_type, content_hash);
+    }
+
+    /// Proposes a new valuation, optionally backed by an attached appraisal
+    /// (owner, admin or verifier); fails while another one awaits sign-off
+    pub fn propose_valuation(
+        env: Env,
+        proposer: Address,
+        property_id: u64,
+        valuation: i128,
+        appraisal: Option<BytesN<32>>,
+    ) {
+        proposer.require_auth();
+        PauseControl::require_not_paused(&env);
+        RegistryControl::propose_valuation(&env, &proposer, property_id, valuation, appraisal);
+    }
+
+    /// Signs off the pending valuation of a property (verifiers only)
+    ///
+    /// `valuation` must match the pending valuation the verifier reviewed.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the valuation reached the quorum and took effect
+    pub fn approve_valuation(
+        env: Env,
+        verifier: Address,
+        property_id: u64,
+        valuation: i128,
+    ) -> bool {
+        verifier.require_auth();
+        PauseControl::require_not_paused(&env);
+        RegistryControl::approve_valuation(&env, &verifier, property_id, valuation)
+    }
+
+    /// Rejects the pending valuation of a property; the admin drops it outright,
+    /// verifiers drop it once their rejections reach the quorum
+    ///
+    /// # Returns
+    /// * `bool` - Whether the valuation was dropped
+    pub fn reject_valuation(env: Env, caller: Address, property_id: u64) -> bool {
+        caller.require_auth();
+        require_active_and_authorized(&env, &caller, Some(&Role::Verifier));
+        RegistryControl::reject_valuation(&env, &caller, property_id)
+    }
+
+    /// Sets how many distinct verifiers must sign off a valuation (admin only)
+    pub fn set_valuation_quorum(env: Env, admin: Address, quorum: u32) {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        RegistryControl::set_quorum(&env, quorum);
+    }
+
+    /// Returns how many distinct verifiers must sign off a valuation
+    pub fn get_valuation_quorum(env: Env) -> u32 {
+        RegistryStorage::get_quorum(&env)
+    }
+
+    /// Returns the documents attached to a property, oldest first
+    pub fn get_documents(env: Env, property_id: u64) -> Vec<PropertyDocument> {
+        RegistryStorage::get_documents(&env, property_id)
+    }
+
+    /// Returns the valuations that took effect for a property, oldest first
+    pub fn get_valuation_history(env: Env, property_id: u64) -> Vec<ValuationRecord> {
+        RegistryStorage::get_history(&env, property_id)
+    }
+
+    /// Returns the valuation of a property awaiting sign-off
+    pub fn get_pending_valuation(env: Env, property_id: u64) -> Option<PendingValuation> {
+        RegistryStorage::get_pending(&env, property_id)
+    }
+
+    /// Returns the verifiers that rejected the valuation awaiting sign-off
+    pub fn get_valuation_rejections(env: Env, property_id: u64) -> Vec<Address> {
+        RegistryStorage::get_rejections(&env, property_id)
+    }
+
+    /// Returns the documents and valuation history of a property
+    pub fn get_audit_trail(env: Env, property_id: u64) -> PropertyAuditTrail {
+        RegistryControl::audit_trail(&env, property_id)
+    }
+
+    // =========================================================================
+    // Share Token (SEP-41, scoped per property)
+    // =========================================================================
+
+    /// Pauses share transfers and dividend claims of a single property
+    /// (admin, pauser or guardian; guardians can only add flags)
+    pub fn set_property_paused_functions(env: Env, caller: Address, property_id: u64, paused: u32) {
+        caller.require_auth();
+        ShareToken::set_paused_functions(&env, &caller, property_id, paused);
+    }
+
+    /// Returns the functions paused for a property
+    pub fn get_property_paused_functions(env: Env, property_id: u64) -> u32 {
+        ShareToken::paused_functions(&env, property_id)
+    }
+
+    /// Returns the share balance of an account for a property
+    pub fn balance(env: Env, property_id: u64, id: Address) -> i128 {
+        shares::get_balance(&env, property_id, &id) as i128
+    }
+
+    /// Transfers shares of a property from `from` to `to`
+    pub fn transfer(env: Env, property_id: u64, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        ShareToken::require_function_active(&env, property_id, PAUSE_SHARE_TRANSFER);
+        ShareToken::transfer(
+            &env,
+            property_id,
+            &from,
+            &to,
+            ShareToken::to_shares(&env, amount),
+        );
+    }
+
+    /// Transfers shares of a property using a previously approved allowance
+    pub fn transfer_from(
+        env: Env,
+        property_id: u64,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        spender.require_auth();
+        ShareToken::require_function_active(&env, property_id, PAUSE_SHARE_TRANSFER);
+        ShareToken::transfer_from(
+            &env,
+            property_id,
+            &spender,
+            &from,
+            &to,
+            ShareToken::to_shares(&env, amount),
+        );
+    }
+
+    /// Allows `spender` to transfer up to `amount` shares until `expiration_ledger`
+    pub fn approve(
+        env: Env,
+        property_id: u64,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        from.require_auth();
+        ShareToken::approve(
+            &env,
+            property_id,
+            &from,
+            &spender,
+            amount,
+            expiration_ledger,
+        );
+    }
+
+    /// Returns the remaining allowance of `spender` over `from`'s shares
+    pub fn allowance(env: Env, property_id: u64, from: Address, spender: Address) -> i128 {
+        shares::get_allowance(&env, property_id, &from, &spender)
+    }
+
+    /// Returns the number of decimals used by property shares
+    pub fn decimals(env: Env, property_id: u64) -> u32 {
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        TokenConfig::load(&env)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+            .decimals
+    }
+
+    /// Returns the share token name of a property
+    pub fn name(env: Env, property_id: u64) -> String {
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound))
+            .name
+    }
+
+    /// Returns the share token symbol of a property
+    pub fn symbol(env: Env, property_id: u64) -> String {
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        TokenConfig::load(&env)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+            .symbol
+    }
+
+    // =========================================================================
+    // Compliance
+    // =========================================================================
+
+    /// Sets the transfer rules of a property (admin or compliance)
+    ///
+    /// Once rules exist, only allowlisted investors can receive the shares.
+    pub fn set_compliance_rules(
+        env: Env,
+        officer: Address,
+        property_id: u64,
+        rules: ComplianceRules,
+    ) {
+        officer.require_auth();
+        require_active_and_authorized(&env, &officer, Some(&Role::Compliance));
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        ComplianceStorage::set_rules(&env, property_id, &rules);
+    }
+
+    /// Adds an investor to a property's KYC allowlist (admin or compliance)
+    pub fn allowlist_investor(
+        env: Env,
+        officer: Address,
+        property_id: u64,
+        investor: Address,
+        jurisdiction: String,
+    ) {
+        officer.require_auth();
+        require_active_and_authorized(&env, &officer, Some(&Role::Compliance));
+        PropertyMetadata::load(&env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        ComplianceStorage::set_investor(
+            &env,
+            property_id,
+            &investor,
+            &Investor {
+                jurisdiction: jurisdiction.clone(),
+                approved_at: env.ledger().timestamp(),
+            },
+        );
+        ComplianceEvents::investor_allowlisted(
+            &env,
+            property_id_to_string(&env, property_id),
+            investor,
+            jurisdiction,
+        );
+    }
+
+    /// Removes an investor from a property's KYC allowlist (admin or compliance)
+    pub fn remove_investor(env: Env, officer: Address, property_id: u64, investor: Address) {
+        officer.require_auth();
+        require_active_and_authorized(&env, &officer, Some(&Role::Compliance));
+        ComplianceStorage::remove_investor(&env, property_id, &investor);
+        ComplianceEvents::investor_removed(
+            &env,
+            property_id_to_string(&env, property_id),
+            investor,
+        );
+    }
+
+    /// Freezes or unfreezes an address for a property (admin or compliance)
+    pub fn set_frozen(
+        env: Env,
+        officer: Address,
+        property_id: u64,
+        account: Address,
+        frozen: bool,
+    ) {
+        officer.require_auth();
+        require_active_and_authorized(&env, &officer, Some(&Role::Compliance));
+        ComplianceStorage::set_frozen(&env, property_id, &account, frozen);
+        ComplianceEvents::account_frozen(
+            &env,
+            property_id_to_string(&env, property_id),
+            account,
+            frozen,
+        );
+    }
+
+    /// Returns the transfer rules of a property
+    pub fn get_compliance_rules(env: Env, property_id: u64) -> Option<ComplianceRules> {
+        ComplianceStorage::get_rules(&env, property_id)
+    }
+
+    /// Returns an investor's allowlist entry for a property
+    pub fn get_investor(env: Env, property_id: u64, investor: Address) -> Option<Investor> {
+        ComplianceStorage::get_investor(&env, property_id, &investor)
+    }
+
+    /// Returns whether an address is frozen for a property
+    pub fn is_frozen(env: Env, property_id: u64, account: Address) -> bool {
+        ComplianceStorage::is_frozen(&env, property_id, &account)
+    }
+
+    /// Returns the timestamp until which a holder's shares are locked
+    pub fn get_locked_until(env: Env, property_id: u64, holder: Address) -> u64 {
+        ComplianceStorage::get_locked_until(&env, property_id, &holder)
+    }
+
+    // =========================================================================
+    // Primary Offering
+    // =========================================================================
+
+    /// Opens a fixed-price sale of a verified property's shares (property owner only)
+    ///
+    /// # Returns
+    /// * `u64` - ID of the new offering
+    pub fn open_offering(env: Env, owner: Address, property_id: u64, terms: OfferingTerms) -> u64 {
+        owner.require_auth();
+        PauseControl::require_not_paused(&env);
+        OfferingControl::open(&env, &owner, property_id, terms)
+    }
+
+    /// Buys shares in an open offering, escrowing the payment
+    pub fn buy_shares(env: Env, buyer: Address, offering_id: u64, shares: u64) {
+        buyer.require_auth();
+        PauseControl::require_not_paused(&env);
+        OfferingControl::buy(&env, &buyer, offering_id, shares);
+    }
+
+    /// Closes an offering after its window ended or it sold out (callable by anyone)
+    pub fn close_offering(env: Env, offering_id: u64) -> OfferingStatus {
+        PauseControl::require_not_paused(&env);
+        OfferingControl::close(&env, offering_id)
+    }
+
+    /// Claims purchased shares, or a refund if the offering missed its soft cap
+    pub fn claim_offering(env: Env, buyer: Address, offering_id: u64) {
+        buyer.require_auth();
+        PauseControl::require_not_paused(&env);
+        OfferingControl::claim(&env, &buyer, offering_id);
+    }
+
+    /// Returns an offering by ID
+    pub fn get_offering(env: Env, offering_id: u64) -> ShareOffering {
+        OfferingStorage::get(&env, offering_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound))
+    }
+
+    /// Returns a buyer's purchase in an offering
+    pub fn get_purchase(env: Env, offering_id: u64, buyer: Address) -> Option<Purchase> {
+        OfferingStorage::get_purchase(&env, offering_id, &buyer)
+    }
+
+    // =========================================================================
+    // Secondary Market
+    // =========================================================================
+
+    /// Returns the secondary market configuration
+    pub fn get_market_config(env: Env) -> Option<MarketConfig> {
+        MarketStorage::get_config(&env)
+    }
+
+    /// Places a limit order, escrowing the shares or the quote tokens
+    ///
+    /// # Returns
+    /// * `u64` - ID of the new order
+    pub fn place_order(
+        env: Env,
+        owner: Address,
+        property_id: u64,
+        side: OrderSide,
+        price: i128,
+        amount: u64,
+    ) -> u64 {
+        owner.require_auth();
+        ShareToken::require_function_active(&env, property_id, PAUSE_SHARE_TRANSFER);
+        MarketControl::place(&env, &owner, property_id, side, price, amount)
+    }
+
+    /// Cancels an open order and returns its unfilled remainder
+    ///
+    /// Allowed while paused so escrowed funds can always be recovered.
+    pub fn cancel_order(env: Env, owner: Address, order_id: u64) {
+        owner.require_auth();
+        MarketControl::cancel(&env, &owner, order_id);
+    }
+
+    /// Matches crossing orders of a property's book (callable by anyone)
+    ///
+    /// # Returns
+    /// * `u32` - Number of trades executed
+    pub fn match_orders(env: Env, property_id: u64, max_steps: u32) -> u32 {
+        ShareToken::require_function_active(&env, property_id, PAUSE_SHARE_TRANSFER);
+        MarketControl::match_orders(&env, property_id, max_steps)
+    }
+
+    /// Returns an order by ID
+    pub fn get_order(env: Env, order_id: u64) -> Order {
+        MarketStorage::get_order(&env, order_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound))
+    }
+
+    /// Returns the open orders of one side of a property's book, best first
+    pub fn get_order_book(env: Env, property_id: u64, side: OrderSide) -> Vec<Order> {
+        let mut orders = Vec::new(&env);
+        for order_id in MarketStorage::get_book(&env, property_id, side).iter() {
+            orders.push_back(
+                MarketStorage::get_order(&env, order_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound)),
+            );
+        }
+        orders
+    }
+
+    // =========================================================================
+    // Dividends
+    // =========================================================================
+
+    /// Deposits rental income to be distributed pro-rata to shareholders (property owner only)
+    pub fn distribute_dividends(
+        env: Env,
+        owner: Address,
+        property_id: u64,
+        payment_token: Address,
+        amount: i128,
+    ) {
+        owner.require_auth();
+        PauseControl::require_not_paused(&env);
+        DividendControl::distribute(&env, &owner, property_id, &payment_token, amount);
+    }
+
+    /// Claims all dividends accrued by a shareholder
+    ///
+    /// # Returns
+    /// * `i128` - Amount paid out (0 if nothing was owed)
+    pub fn claim_dividends(env: Env, holder: Address, property_id: u64) -> i128 {
+        holder.require_auth();
+        ShareToken::require_function_active(&env, property_id, PAUSE_DIVIDEND_CLAIM);
+        DividendControl::claim(&env, property_id, &holder)
+    }
+
+    /// Returns the dividends a shareholder can currently claim
+    pub fn get_claimable_dividends(env: Env, property_id: u64, holder: Address) -> i128 {
+        DividendControl::claimable(&env, property_id, &holder)
+    }
+
+    // =========================================================================
+    // Governance
+    // =========================================================================
+
+    /// Updates the voting period, quorum and approval threshold (admin only)
+    pub fn set_governance_config(env: Env, admin: Address, config: GovernanceConfig) {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        GovernanceStorage::set_config(&env, &config);
+    }
+
+    /// Returns the governance configuration
+    pub fn get_governance_config(env: Env) -> GovernanceConfig {
+        GovernanceStorage::get_config(&env)
+    }
+
+    /// Creates a proposal for a property's shareholders (shareholders only)
+    ///
+    /// # Returns
+    /// * `u64` - ID of the new proposal
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        property_id: u64,
+        action: ProposalAction,
+    ) -> u64 {
+        proposer.require_auth();
+        PauseControl::require_not_paused(&env);
+        GovernanceControl::create(&env, &proposer, property_id, action)
+    }
+
+    /// Votes on a proposal with the shares held at its snapshot
+    ///
+    /// # Returns
+    /// * `u64` - Voting power used
+    pub fn vote(env: Env, voter: Address, proposal_id: u64, support: bool) -> u64 {
+        voter.require_auth();
+        PauseControl::require_not_paused(&env);
+        GovernanceControl::vote(&env, &voter, proposal_id, support)
+    }
+
+    /// Finalizes a proposal after voting ends, executing it if approved (callable by anyone)
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> ProposalStatus {
+        PauseControl::require_not_paused(&env);
+        GovernanceControl::execute(&env, proposal_id)
+    }
+
+    /// Returns a proposal by ID
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Proposal {
+        GovernanceStorage::get_proposal(&env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound))
+    }
+
+    /// Returns a holder's share balance at the end of a ledger
+    pub fn get_balance_at(env: Env, property_id: u64, holder: Address, ledger: u32) -> u64 {
+        shares::get_balance_at(&env, property_id, &holder, ledger)
+    }
+
+    /// Returns the dividend policy adopted by a property's shareholders
+    pub fn get_dividend_policy(env: Env, property_id: u64) -> Option<DividendPolicy> {
+        GovernanceStorage::get_dividend_policy(&env, property_id)
+    }
+
+    // =========================================================================
+    // Buyout
+    // =========================================================================
+
+    /// Starts a buyout of all shares, freezing trading
+    ///
+    /// The bidder passes the ID of the approved `SellProperty` proposal naming
+    /// them as buyer.
+    pub fn start_buyout(
+        env: Env,
+        bidder: Address,
+        property_id: u64,
+        terms: BuyoutTerms,
+        proposal_id: u64,
+    ) {
+        bidder.require_auth();
+        PauseControl::require_not_paused(&env);
+        BuyoutControl::start(&env, &bidder, property_id, terms, proposal_id);
+    }
+
+    /// Redeems all of a holder's shares for their pro-rata buyout payout
+    ///
+    /// Remains available after delisting, paying out of the buyout escrow,
+    /// until the claim period (`BUYOUT_CLAIM_PERIOD` past the deadline) ends.
+    ///
+    /// # Returns
+    /// * `i128` - Payout
+    pub fn redeem_shares(env: Env, holder: Address, property_id: u64) -> i128 {
+        holder.require_auth();
+        PauseControl::require_not_paused(&env);
+        BuyoutControl::redeem(&env, &holder, property_id)
+    }
+
+    /// Delists a bought-out property after the deadline (callable by anyone)
+    ///
+    /// # Returns
+    /// * `i128` - Payment left in escrow for holders who have not redeemed
+    pub fn finalize_buyout(env: Env, property_id: u64) -> i128 {
+        PauseControl::require_not_paused(&env);
+        BuyoutControl::finalize(&env, property_id)
+    }
+
+    /// Returns payouts left unredeemed after the claim period to the bidder
+    /// (callable by anyone)
+    ///
+    /// # Returns
+    /// * `i128` - Amount returned to the bidder
+    pub fn sweep_buyout(env: Env, property_id: u64) -> i128 {
+        PauseControl::require_not_paused(&env);
+        BuyoutControl::sweep(&env, property_id)
+    }
+
+    /// Returns the buyout of a property
+    pub fn get_buyout(env: Env, property_id: u64) -> Option<Buyout> {
+        BuyoutStorage::get(&env, property_id)
+    }
+
+    // =========================================================================
+    // Lending Pools
+    // =========================================================================
+
+    /// Creates a lending pool for an asset (admin only)
+    pub fn create_pool(
+        env: Env,
+        admin: Address,
+        pool_id: String,
+        name: String,
+        asset: String,
+        asset_address: Address,
+        params: PoolRiskParams,
+    ) {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        LendingControl::create_pool(&env, &pool_id, name, asset, asset_address, params);
+    }
+
+    /// Deposits assets into a pool
+    ///
+    /// # Returns
+    /// * `i128` - Pool shares minted
+    pub fn deposit(env: Env, depositor: Address, pool_id: String, amount: i128) -> i128 {
+        depositor.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_DEPOSIT);
+        LendingControl::deposit(&env, &depositor, &pool_id, amount)
+    }
+
+    /// Withdraws assets from a pool
+    ///
+    /// # Returns
+    /// * `i128` - Pool shares burned
+    pub fn withdraw(env: Env, withdrawer: Address, pool_id: String, amount: i128) -> i128 {
+        withdrawer.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_WITHDRAW);
+        LendingControl::withdraw(&env, &withdrawer, &pool_id, amount)
+    }
+
+    /// Locks collateral in a pool's borrow position
+    pub fn add_collateral(
+        env: Env,
+        borrower: Address,
+        pool_id: String,
+        collateral: Collateral,
+        amount: i128,
+    ) {
+        borrower.require_auth();
+        PauseControl::require_not_paused(&env);
+        LendingControl::add_collateral(&env, &borrower, &pool_id, collateral, amount);
+    }
+
+    /// Unlocks collateral from a pool's borrow position
+    pub fn remove_collateral(env: Env, borrower: Address, pool_id: String, amount: i128) {
+        borrower.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_WITHDRAW);
+        LendingControl::remove_collateral(&env, &borrower, &pool_id, amount);
+    }
+
+    /// Borrows assets from a pool against collateral posted in any pool
+    ///
+    /// # Returns
+    /// * `i128` - Account health factor after the borrow (PRECISION scaled)
+    pub fn borrow(env: Env, borrower: Address, pool_id: String, amount: i128) -> i128 {
+        borrower.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_BORROW);
+        LendingControl::borrow(&env, &borrower, &pool_id, amount)
+    }
+
+    /// Repays a borrow position; a full repayment releases the collateral
+    /// unless it still backs debt in other pools
+    ///
+    /// # Returns
+    /// * `i128` - Debt remaining after the repayment
+    pub fn repay(env: Env, borrower: Address, pool_id: String, amount: i128) -> i128 {
+        borrower.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_REPAY);
+        LendingControl::repay(&env, &borrower, &pool_id, amount)
+    }
+
+    /// Lends pool liquidity to a receiver contract for the duration of its
+    /// `on_flash_loan` callback; principal and fee must be back when it returns
+    ///
+    /// # Returns
+    /// * `i128` - Fee paid
+    pub fn flash_loan(
+        env: Env,
+        receiver: Address,
+        pool_id: String,
+        amount: i128,
+        params: Bytes,
+    ) -> i128 {
+        receiver.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_BORROW);
+        LendingControl::flash_loan(&env, &receiver, &pool_id, amount, params)
+    }
+
+    /// Accrues interest on a pool up to the current ledger time (callable by anyone)
+    ///
+    /// # Returns
+    /// * `i128` - Interest accrued
+    pub fn accrue_interest(env: Env, pool_id: String) -> i128 {
+        let pool = PoolStorage::get(&env, &pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+        LendingControl::accrue_interest(&env, &pool)
+    }
+
+    /// Liquidates an unhealthy account (admin or liquidator)
+    ///
+    /// Repays debt owed to `pool_id` and seizes the collateral posted in
+    /// `collateral_pool_id`.
+    ///
+    /// # Returns
+    /// * `i128` - Collateral seized
+    pub fn liquidate(
+        env: Env,
+        liquidator: Address,
+        borrower: Address,
+        pool_id: String,
+        collateral_pool_id: String,
+        amount: i128,
+    ) -> i128 {
+        liquidator.require_auth();
+        require_active_and_authorized(&env, &liquidator, Some(&Role::Liquidator));
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_LIQUIDATE);
+        LendingControl::require_function_active(&env, &collateral_pool_id, PAUSE_LIQUIDATE);
+        LendingControl::liquidate(
+            &env,
+            &liquidator,
+            &borrower,
+            &pool_id,
+            &collateral_pool_id,
+            amount,
+        )
+    }
+
+    /// Burns an exact number of deposit receipts for pool assets at the
+    /// current exchange rate
+    ///
+    /// # Returns
+    /// * `i128` - Pool assets withdrawn
+    pub fn redeem(env: Env, withdrawer: Address, pool_id: String, receipts: i128) -> i128 {
+        withdrawer.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_WITHDRAW);
+        LendingControl::redeem(&env, &withdrawer, &pool_id, receipts)
+    }
+
+    /// Pauses individual functions of a single pool
+    /// (admin, pauser or guardian; guardians can only add flags)
+    pub fn set_pool_paused_functions(env: Env, caller: Address, pool_id: String, paused: u32) {
+        caller.require_auth();
+        LendingControl::set_paused_functions(&env, &caller, &pool_id, paused);
+    }
+
+    /// Returns the functions paused for a pool
+    pub fn get_pool_paused_functions(env: Env, pool_id: String) -> u32 {
+        PoolStorage::get_paused(&env, &pool_id)
+    }
+
+    /// Returns a pool's caps
+    pub fn get_pool_caps(env: Env, pool_id: String) -> PoolCaps {
+        PoolStorage::get_caps(&env, &pool_id)
+    }
+
+    /// Sends accumulated pool reserves to a treasury (admin only)
+    pub fn withdraw_reserves(
+        env: Env,
+        admin: Address,
+        pool_id: String,
+        treasury: Address,
+        amount: i128,
+    ) {
+        admin.require_auth();
+        require_active_and_authorized(&env, &admin, None);
+        LendingControl::withdraw_reserves(&env, &pool_id, &treasury, amount);
+    }
+
+    /// Returns the reserves accumulated by a pool
+    pub fn get_reserves(env: Env, pool_id: String) -> i128 {
+        PoolStorage::get_reserves(&env, &pool_id)
+    }
+
+    /// Returns a pool's interest rate model
+    pub fn get_rate_model(env: Env, pool_id: String) -> InterestRateModel {
+        InterestStorage::get_model(&env, &pool_id)
+    }
+
+    /// Returns a pool's utilization and borrow/supply APR and APY
+    pub fn get_current_rates(env: Env, pool_id: String) -> PoolRates {
+        LendingControl::current_rates(&env, &pool_id)
+    }
+
+    /// Returns the protocol-wide lending configuration
+    pub fn get_lending_config(env: Env) -> LendingConfig {
+        LendingConfigStorage::get(&env)
+    }
+
+    /// Adds pool assets to a pool's insurance fund (callable by anyone)
+    pub fn fund_insurance(env: Env, funder: Address, pool_id: String, amount: i128) {
+        funder.require_auth();
+        PauseControl::require_not_paused(&env);
+        LendingControl::fund_insurance(&env, &funder, &pool_id, amount);
+    }
+
+    /// Returns the balance of a pool's insurance fund
+    pub fn get_insurance_fund(env: Env, pool_id: String) -> i128 {
+        PoolStorage::get_insurance(&env, &pool_id)
+    }
+
+    /// Returns the bad debt written off in a pool
+    pub fn get_bad_debt(env: Env, pool_id: String) -> i128 {
+        PoolStorage::get_bad_debt(&env, &pool_id)
+    }
+
+    /// Returns the current debt of a borrow position, including accrued interest
+    pub fn get_debt(env: Env, borrower: Address, pool_id: String) -> i128 {
+        PositionStorage::get_borrow(&env, &borrower, &pool_id)
+            .map(|position| PositionStorage::calculate_current_debt(&env, &position))
+            .unwrap_or(0)
+    }
+
+    /// Returns an account's health factor across all pools (PRECISION scaled)
+    pub fn get_account_health(env: Env, user: Address) -> i128 {
+        LendingControl::account_health(&env, &user)
+    }
+
+    /// Returns an account's supplied, collateral and debt values across all pools
+    pub fn get_account_summary(env: Env, user: Address) -> AccountSummary {
+        LendingControl::account_summary(&env, &user)
+    }
+
+    /// Returns the largest amount an account can still borrow from a pool
+    pub fn max_borrowable(env: Env, user: Address, pool_id: String) -> i128 {
+        LendingControl::max_borrowable(&env, &user, &pool_id)
+    }
+
+    /// Returns a lending pool
+    pub fn get_pool(env: Env, pool_id: String) -> LendingPool {
+        PoolStorage::get(&env, &pool_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound))
+    }
+
+    /// Returns a user's deposit position in a pool
+    pub fn get_deposit(env: Env, depositor: Address, pool_id: String) -> Option<DepositPosition> {
+        PositionStorage::get_deposit(&env, &depositor, &pool_id)
+    }
+
+    /// Returns a user's borrow position in a pool
+    pub fn get_borrow(env: Env, borrower: Address, pool_id: String) -> Option<BorrowPosition> {
+        PositionStorage::get_borrow(&env, &borrower, &pool_id)
+    }
+
+    // =========================================================================
+    // Deposit Receipts (SEP-41, scoped per pool)
+    // =========================================================================
+
+    /// Returns the deposit receipt balance of an account for a pool
+    pub fn receipt_balance(env: Env, pool_id: String, id: Address) -> i128 {
+        ReceiptToken::balance(&env, &pool_id, &id)
+    }
+
+    /// Transfers deposit receipts of a pool from `from` to `to`
+    pub fn receipt_transfer(env: Env, pool_id: String, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_SHARE_TRANSFER);
+        ReceiptToken::transfer(&env, &pool_id, &from, &to, amount);
+    }
+
+    /// Transfers deposit receipts of a pool using a previously approved allowance
+    pub fn receipt_transfer_from(
+        env: Env,
+        pool_id: String,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        spender.require_auth();
+        LendingControl::require_function_active(&env, &pool_id, PAUSE_SHARE_TRANSFER);
+        ReceiptToken::transfer_from(&env, &pool_id, &spender, &from, &to, amount);
+    }
+
+    /// Allows `spender` to transfer up to `amount` receipts until `expiration_ledger`
+    pub fn receipt_approve(
+        env: Env,
+        pool_id: String,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        from.require_auth();
+        ReceiptToken::approve(&env, &pool_id, &from, &spender, amount, expiration_ledger);
+    }
+
+    /// Returns the remaining allowance of `spender` over `from`'s receipts
+    pub fn receipt_allowance(env: Env, pool_id: String, from: Address, spender: Address) -> i128 {
+        ReceiptToken::allowance(&env, &pool_id, &from, &spender)
+    }
+
+    /// Returns the total receipts issued by a pool
+    pub fn receipt_total_supply(env: Env, pool_id: String) -> i128 {
+        PoolStorage::get_total_shares(&env, &pool_id)
+    }
+
+    /// Returns the pool assets one receipt redeems for (PRECISION scaled)
+    pub fn receipt_exchange_rate(env: Env, pool_id: String) -> i128 {
+        ReceiptToken::exchange_rate(&env, &pool_id)
+    }
+
+    // =========================================================================
+    // Oracle
+    // =========================================================================
+
+    /// Posts a price for an asset or a valuation for a property (admin or oracle)
+    pub fn set_price(env: Env, oracle: Address, feed: PriceFeed, price: i128) {
+        oracle.require_auth();
+        require_active_and_authorized(&env, &oracle, Some(&Role::Oracle));
+        PriceOracle::post(&env, &oracle, &feed, price);
+    }
+
+    /// Returns the median of the fresh reports of a feed, if any
+    pub fn get_price(env: Env, feed: PriceFeed) -> Option<PriceData> {
+        PriceOracle::aggregate(&env, &feed)
+    }
+
+    /// Returns the latest report of every oracle for a feed
+    pub fn get_price_reports(env: Env, feed: PriceFeed) -> Vec<PriceReport> {
+        OracleStorage::get_reports(&env, &feed)
+    }
+
+    /// Returns the oracle staleness and deviation guards
+    pub fn get_oracle_config(env: Env) -> OracleConfig {
+        OracleStorage::get_config(&env)
     }
 }
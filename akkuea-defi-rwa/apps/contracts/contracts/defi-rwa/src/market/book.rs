@@ -0,0 +1,198 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+use super::keys::{market_bump, MarketKey};
+use crate::errors::ContractError;
+
+/// Maximum maker or taker fee in basis points (10%)
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+/// Maximum number of open orders on one side of a property's book
+pub const MAX_BOOK_ORDERS: u32 = 100;
+
+/// Secondary market configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MarketConfig {
+    /// Token shares are priced and settled in (e.g., a USDC contract)
+    pub quote_token: Address,
+    /// Account receiving trading fees
+    pub treasury: Address,
+    /// Fee paid by the resting order of a trade, in basis points
+    pub maker_fee_bps: u32,
+    /// Fee paid by the incoming order of a trade, in basis points
+    pub taker_fee_bps: u32,
+}
+
+/// Side of an order
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[contracttype]
+pub enum OrderSide {
+    /// Buy shares with the quote token
+    Buy,
+    /// Sell shares for the quote token
+    Sell,
+}
+
+/// Lifecycle of an order
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum OrderStatus {
+    /// Resting in the book, possibly partially filled
+    Open,
+    /// Completely filled
+    Filled,
+    /// Cancelled with its remainder returned to the owner
+    Cancelled,
+    /// Pulled from the book while its owner is frozen; the remainder stays
+    /// escrowed until the owner cancels it
+    Suspended,
+}
+
+/// Limit order for a property's shares
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Order {
+    /// Unique order identifier
+    pub id: u64,
+    /// Property whose shares are traded
+    pub property_id: u64,
+    /// Order owner
+    pub owner: Address,
+    /// Buy or sell
+    pub side: OrderSide,
+    /// Limit price of one share in quote token units
+    pub price: i128,
+    /// Shares to trade
+    pub amount: u64,
+    /// Shares traded so far
+    pub filled: u64,
+    /// Quote tokens held in escrow for a buy order, including fees
+    pub escrow: i128,
+    /// Timestamp at which the order was placed
+    pub created_at: u64,
+    /// Current status
+    pub status: OrderStatus,
+}
+
+impl Order {
+    /// Shares still to be traded
+    pub fn remaining(&self) -> u64 {
+        self.amount - self.filled
+    }
+
+    /// Whether this order takes priority over `other` on the same side
+    ///
+    /// Better prices come first (highest bid, lowest ask); equal prices are
+    /// served in the order they were placed.
+    pub fn has_priority_over(&self, other: &Order) -> bool {
+        if self.price != other.price {
+            return match self.side {
+                OrderSide::Buy => self.price > other.price,
+                OrderSide::Sell => self.price < other.price,
+            };
+        }
+        self.id < other.id
+    }
+}
+
+/// Market storage helpers
+pub struct MarketStorage;
+
+impl MarketStorage {
+    /// Get market configuration
+    pub fn get_config(env: &Env) -> Option<MarketConfig> {
+        env.storage().instance().get(&MarketKey::MarketConfig)
+    }
+
+    /// Store market configuration
+    pub fn set_config(env: &Env, config: &MarketConfig) {
+        if config.maker_fee_bps > MAX_FEE_BPS || config.taker_fee_bps > MAX_FEE_BPS {
+            panic_with_error!(env, ContractError::InvalidConfig);
+        }
+        if let Some(current) = Self::get_config(env) {
+            if current.quote_token != config.quote_token {
+                panic_with_error!(env, ContractError::InvalidConfig);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&MarketKey::MarketConfig, config);
+    }
+
+    /// Generate the next order ID
+    pub fn next_id(env: &Env) -> u64 {
+        let key = MarketKey::OrderCounter;
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        env.storage().instance().set(&key, &next);
+        next
+    }
+
+    /// Store order
+    pub fn set_order(env: &Env, order: &Order) {
+        let key = MarketKey::Order(order.id);
+        env.storage().persistent().set(&key, order);
+        env.storage().persistent().extend_ttl(
+            &key,
+            market_bump::PERSISTENT_BUMP,
+            market_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get order by ID
+    pub fn get_order(env: &Env, order_id: u64) -> Option<Order> {
+        let key = MarketKey::Order(order_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get the open order IDs of one side of a property's book, best first
+    pub fn get_book(env: &Env, property_id: u64, side: OrderSide) -> Vec<u64> {
+        let key = MarketKey::Book(property_id, side);
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_book(env: &Env, property_id: u64, side: OrderSide, book: &Vec<u64>) {
+        let key = MarketKey::Book(property_id, side);
+        env.storage().persistent().set(&key, book);
+        env.storage().persistent().extend_ttl(
+            &key,
+            market_bump::PERSISTENT_BUMP,
+            market_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Insert an order into its book at its price-time priority position
+    ///
+    /// Binary search keeps the number of orders loaded logarithmic in the
+    /// book size.
+    pub fn insert_into_book(env: &Env, order: &Order) {
+        let mut book = Self::get_book(env, order.property_id, order.side);
+        let (mut low, mut high) = (0, book.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            let resting = Self::get_order(env, book.get_unchecked(middle))
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+            if order.has_priority_over(&resting) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        book.insert(low, order.id);
+        Self::set_book(env, order.property_id, order.side, &book);
+    }
+
+    /// Remove an order from its book
+    pub fn remove_from_book(env: &Env, order: &Order) {
+        let mut book = Self::get_book(env, order.property_id, order.side);
+        if let Some(index) = book.first_index_of(order.id) {
+            book.remove(index);
+            Self::set_book(env, order.property_id, order.side, &book);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use soroban_sdk::{panic_with_error, token, Address, Env};
+
+use super::book::{MarketConfig, MarketStorage, Order, OrderSide, OrderStatus, MAX_BOOK_ORDERS};
+use crate::compliance::{ComplianceControl, ComplianceStorage};
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, MarketEvents, PropertyEvents};
+use crate::oracle::BPS_DENOMINATOR;
+use crate::storage::shares;
+use crate::token::ShareToken;
+
+/// Secondary market flow
+///
+/// Sell orders escrow their shares and buy orders escrow the quote tokens for
+/// the full amount plus the highest fee they could pay. Trades execute at the
+/// resting (maker) order's price; the incoming (taker) order is the one placed
+/// later. Each side of a book holds at most `MAX_BOOK_ORDERS` orders; once
+/// full, a new order must beat the worst resting order, which is cancelled to
+/// make room (or suspended, if it is an ask whose seller is frozen).
+pub struct MarketControl;
+
+impl MarketControl {
+    /// Load the market configuration
+    pub fn config(env: &Env) -> MarketConfig {
+        MarketStorage::get_config(env)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+    }
+
+    /// Fee on a quote amount
+    fn fee(notional: i128, fee_bps: u32) -> i128 {
+        notional * fee_bps as i128 / BPS_DENOMINATOR
+    }
+
+    /// Place a limit order, escrowing shares or quote tokens
+    ///
+    /// # Returns
+    /// * `u64` - ID of the new order
+    pub fn place(
+        env: &Env,
+        owner: &Address,
+        property_id: u64,
+        side: OrderSide,
+        price: i128,
+        amount: u64,
+    ) -> u64 {
+        let config = Self::config(env);
+        if price <= 0 {
+            panic_with_error!(env, ContractError::InvalidPrice);
+        }
+        if amount == 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        let metadata = ShareToken::load_tradable(env, property_id);
+        let contract = env.current_contract_address();
+
+        let book = MarketStorage::get_book(env, property_id, side);
+        if book.len() >= MAX_BOOK_ORDERS {
+            let worst = MarketStorage::get_order(env, book.last_unchecked())
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+            let improves = match side {
+                OrderSide::Buy => price > worst.price,
+                OrderSide::Sell => price < worst.price,
+            };
+            if !improves {
+                panic_with_error!(env, ContractError::CapExceeded);
+            }
+            // Escrowed shares cannot be returned to a frozen seller
+            if worst.side == OrderSide::Sell
+                && ComplianceStorage::is_frozen(env, property_id, &worst.owner)
+            {
+                Self::suspend_order(env, worst);
+            } else {
+                Self::close_order(env, &config, worst);
+            }
+        }
+
+        let escrow = match side {
+            OrderSide::Sell => {
+                ShareToken::transfer(env, property_id, owner, &contract, amount);
+                0
+            }
+            OrderSide::Buy => {
+                let holding = shares::get_balance(env, property_id, owner);
+                ComplianceControl::require_can_receive(
+                    env,
+                    &metadata,
+                    owner,
+                    holding.saturating_add(amount),
+                );
+                let notional = price
+                    .checked_mul(amount as i128)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+                let max_fee_bps = config.maker_fee_bps.max(config.taker_fee_bps);
+                let escrow = notional + Self::fee(notional, max_fee_bps);
+                token::Client::new(env, &config.quote_token).transfer(owner, &contract, &escrow);
+                escrow
+            }
+        };
+
+        let order = Order {
+            id: MarketStorage::next_id(env),
+            property_id,
+            owner: owner.clone(),
+            side,
+            price,
+            amount,
+            filled: 0,
+            escrow,
+            created_at: env.ledger().timestamp(),
+            status: OrderStatus::Open,
+        };
+        MarketStorage::set_order(env, &order);
+        MarketStorage::insert_into_book(env, &order);
+
+        MarketEvents::order_placed(
+            env,
+            property_id_to_string(env, property_id),
+            order.id,
+            owner.clone(),
+            side,
+            price,
+            amount as i128,
+        );
+
+        order.id
+    }
+
+    /// Cancel an open or suspended order and return its unfilled remainder
+    pub fn cancel(env: &Env, owner: &Address, order_id: u64) {
+        let order = MarketStorage::get_order(env, order_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if order.owner != *owner {
+            panic_with_error!(env, ContractError::Unauthorized);
+        }
+        if order.status != OrderStatus::Open && order.status != OrderStatus::Suspended {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        Self::close_order(env, &Self::config(env), order);
+    }
+
+    /// Return an order's escrowed remainder to its owner and drop it from the book
+    fn close_order(env: &Env, config: &MarketConfig, mut order: Order) {
+        let contract = env.current_contract_address();
+        match order.side {
+            OrderSide::Sell => {
+                if order.remaining() > 0 {
+                    ShareToken::transfer(
+                        env,
+                        order.property_id,
+                        &contract,
+                        &order.owner,
+                        order.remaining(),
+                    );
+                }
+            }
+            OrderSide::Buy => {
+                if order.escrow > 0 {
+                    token::Client::new(env, &config.quote_token).transfer(
+                        &contract,
+                        &order.owner,
+                        &order.escrow,
+                    );
+                }
+            }
+        }
+
+        MarketStorage::remove_from_book(env, &order);
+        let returned = match order.side {
+            OrderSide::Sell => order.remaining() as i128,
+            OrderSide::Buy => order.escrow,
+        };
+        order.escrow = 0;
+        order.status = OrderStatus::Cancelled;
+        MarketStorage::set_order(env, &order);
+
+        MarketEvents::order_cancelled(
+            env,
+            property_id_to_string(env, order.property_id),
+            order.id,
+            order.owner.clone(),
+            returned,
+        );
+    }
+
+    /// Drop an order from the book without returning its escrow
+    fn suspend_order(env: &Env, mut order: Order) {
+        MarketStorage::remove_from_book(env, &order);
+        order.status = OrderStatus::Suspended;
+        MarketStorage::set_order(env, &order);
+
+        MarketEvents::order_suspended(
+            env,
+            property_id_to_string(env, order.property_id),
+            order.id,
+            order.owner.clone(),
+        );
+    }
+
+    /// Match crossing orders of a property's book in price-time priority
+    ///
+    /// Bids that can no longer receive shares under the property's compliance
+    /// rules are cancelled instead of blocking the book. Asks of frozen sellers
+    /// are suspended without paying them; the shares stay escrowed until the
+    /// seller, once unfrozen, cancels the order.
+    ///
+    /// # Returns
+    /// * `u32` - Number of trades executed
+    pub fn match_orders(env: &Env, property_id: u64, max_steps: u32) -> u32 {
+        let config = Self::config(env);
+        let metadata = ShareToken::load_tradable(env, property_id);
+        let contract = env.current_contract_address();
+        let quote = token::Client::new(env, &config.quote_token);
+
+        let mut trades = 0;
+        for _ in 0..max_steps {
+            let (Some(bid_id), Some(ask_id)) = (
+                MarketStorage::get_book(env, property_id, OrderSide::Buy).first(),
+                MarketStorage::get_book(env, property_id, OrderSide::Sell).first(),
+            ) else {
+                break;
+            };
+            let mut bid = MarketStorage::get_order(env, bid_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+            let mut ask = MarketStorage::get_order(env, ask_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+            if bid.price < ask.price {
+                break;
+            }
+
+            if ComplianceStorage::is_frozen(env, property_id, &ask.owner) {
+                Self::suspend_order(env, ask);
+                continue;
+            }
+
+            let shares = bid.remaining().min(ask.remaining());
+            let holding = shares::get_balance(env, property_id, &bid.owner);
+            if ComplianceControl::check_can_receive(
+                env,
+                &metadata,
+                &bid.owner,
+                holding.saturating_add(shares),
+            )
+            .is_err()
+            {
+                Self::close_order(env, &config, bid);
+                continue;
+            }
+
+            // The earlier order is the maker and sets the price
+            let bid_is_maker = bid.id < ask.id;
+            let price = if bid_is_maker { bid.price } else { ask.price };
+            let (buyer_fee_bps, seller_fee_bps) = if bid_is_maker {
+                (config.maker_fee_bps, config.taker_fee_bps)
+            } else {
+                (config.taker_fee_bps, config.maker_fee_bps)
+            };
+            let notional = price * shares as i128;
+            let buyer_fee = Self::fee(notional, buyer_fee_bps).min(bid.escrow - notional);
+            let seller_fee = Self::fee(notional, seller_fee_bps);
+
+            quote.transfer(&contract, &ask.owner, &(notional - seller_fee));
+            if buyer_fee + seller_fee > 0 {
+                quote.transfer(&contract, &config.treasury, &(buyer_fee + seller_fee));
+            }
+            ShareToken::transfer(env, property_id, &contract, &bid.owner, shares);
+            ComplianceControl::start_lockup(env, property_id, &bid.owner);
+
+            bid.filled += shares;
+            bid.escrow -= notional + buyer_fee;
+            ask.filled += shares;
+
+            MarketEvents::trade_executed(
+                env,
+                property_id_to_string(env, property_id),
+                &bid,
+                &ask,
+                price,
+                shares as i128,
+                buyer_fee,
+                seller_fee,
+            );
+            PropertyEvents::share_sale(
+                env,
+                property_id_to_string(env, property_id),
+                ask.owner.clone(),
+                shares as i128,
+                notional - seller_fee,
+            );
+            trades += 1;
+
+            if ask.remaining() == 0 {
+                ask.status = OrderStatus::Filled;
+                MarketStorage::remove_from_book(env, &ask);
+            }
+            MarketStorage::set_order(env, &ask);
+            if bid.remaining() == 0 {
+                // Refund the escrow left over from price improvement and fees
+                if bid.escrow > 0 {
+                    quote.transfer(&contract, &bid.owner, &bid.escrow);
+                    bid.escrow = 0;
+                }
+                bid.status = OrderStatus::Filled;
+                MarketStorage::remove_from_book(env, &bid);
+            }
+            MarketStorage::set_order(env, &bid);
+        }
+
+        trades
+    }
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::contracttype;
+
+use super::book::OrderSide;
+
+/// Storage key types for the market module
+#[derive(Clone)]
+#[contracttype]
+pub enum MarketKey {
+    /// Quote token, treasury and fee configuration
+    /// Storage: Instance
+    MarketConfig,
+
+    /// Order by order ID
+    /// Storage: Persistent
+    Order(u64),
+
+    /// Open order IDs of one side of a property's book, in priority order
+    /// Storage: Persistent
+    Book(u64, OrderSide),
+
+    /// Counter used to generate order IDs
+    /// Storage: Instance
+    OrderCounter,
+}
+
+/// TTL bump amounts for market storage
+pub mod market_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
@@ -0,0 +1,13 @@
+// Market module for DeFi RWA
+// This module provides the secondary market: a per-property limit order book where
+// shareholders trade shares against a quote token, with both sides escrowed until filled.
+
+mod book;
+mod control;
+mod keys;
+
+pub use book::{
+    MarketConfig, MarketStorage, Order, OrderSide, OrderStatus, MAX_BOOK_ORDERS, MAX_FEE_BPS,
+};
+pub use control::MarketControl;
+pub use keys::{market_bump, MarketKey};
@@ -0,0 +1,244 @@
+use soroban_sdk::{panic_with_error, token, Address, Env};
+
+use super::sale::{OfferingStatus, OfferingStorage, OfferingTerms, Purchase, ShareOffering};
+use crate::compliance::ComplianceControl;
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, PropertyEvents};
+use crate::storage::shares;
+use crate::token::ShareToken;
+
+/// Primary offering flow
+///
+/// Offered shares and buyer payments are held by the contract until the
+/// offering is closed. Buyers then claim either their shares or a refund.
+pub struct OfferingControl;
+
+impl OfferingControl {
+    /// Open a sale window for a verified property, escrowing the offered shares
+    pub fn open(env: &Env, owner: &Address, property_id: u64, terms: OfferingTerms) -> u64 {
+        let metadata = ShareToken::load_tradable(env, property_id);
+        if metadata.owner != *owner {
+            panic_with_error!(env, ContractError::NotPropertyOwner);
+        }
+        if !metadata.is_verified {
+            panic_with_error!(env, ContractError::PropertyNotVerified);
+        }
+        if OfferingStorage::get_active(env, property_id).is_some() {
+            panic_with_error!(env, ContractError::AlreadyExists);
+        }
+        if terms.price_per_share <= 0 {
+            panic_with_error!(env, ContractError::InvalidPrice);
+        }
+        if terms.shares_offered == 0
+            || terms.min_purchase == 0
+            || terms.min_purchase > terms.max_purchase
+            || terms.soft_cap > terms.shares_offered
+        {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+        let now = env.ledger().timestamp();
+        if terms.end_time <= now {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+
+        ShareToken::transfer(
+            env,
+            property_id,
+            owner,
+            &env.current_contract_address(),
+            terms.shares_offered,
+        );
+
+        let offering = ShareOffering {
+            id: OfferingStorage::next_id(env),
+            property_id,
+            owner: owner.clone(),
+            terms,
+            shares_sold: 0,
+            start_time: now,
+            status: OfferingStatus::Open,
+        };
+        OfferingStorage::set(env, &offering);
+        OfferingStorage::set_active(env, property_id, offering.id);
+
+        PropertyEvents::offering_opened(
+            env,
+            property_id_to_string(env, property_id),
+            offering.id,
+            offering.owner.clone(),
+            offering.terms.payment_token.clone(),
+            offering.terms.price_per_share,
+            offering.terms.shares_offered as i128,
+            offering.terms.end_time,
+        );
+
+        offering.id
+    }
+
+    /// Buy shares by transferring the payment token into escrow
+    pub fn buy(env: &Env, buyer: &Address, offering_id: u64, shares: u64) {
+        let mut offering = OfferingStorage::get(env, offering_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if offering.status != OfferingStatus::Open
+            || env.ledger().timestamp() >= offering.terms.end_time
+        {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        if shares < offering.terms.min_purchase {
+            panic_with_error!(env, ContractError::AmountOutOfRange);
+        }
+
+        let mut purchase =
+            OfferingStorage::get_purchase(env, offering_id, buyer).unwrap_or(Purchase {
+                shares: 0,
+                amount_paid: 0,
+                claimed: false,
+            });
+        let bought = purchase
+            .shares
+            .checked_add(shares)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        if bought > offering.terms.max_purchase {
+            panic_with_error!(env, ContractError::AmountOutOfRange);
+        }
+        let metadata = ShareToken::load_tradable(env, offering.property_id);
+        let holding = shares::get_balance(env, offering.property_id, buyer);
+        ComplianceControl::require_can_receive(
+            env,
+            &metadata,
+            buyer,
+            holding.saturating_add(bought),
+        );
+        let sold = offering
+            .shares_sold
+            .checked_add(shares)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        if sold > offering.terms.shares_offered {
+            panic_with_error!(env, ContractError::AmountOutOfRange);
+        }
+
+        let cost = offering
+            .terms
+            .price_per_share
+            .checked_mul(shares as i128)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        token::Client::new(env, &offering.terms.payment_token).transfer(
+            buyer,
+            env.current_contract_address(),
+            &cost,
+        );
+
+        purchase.shares = bought;
+        purchase.amount_paid += cost;
+        OfferingStorage::set_purchase(env, offering_id, buyer, &purchase);
+
+        offering.shares_sold = sold;
+        OfferingStorage::set(env, &offering);
+
+        PropertyEvents::share_purchase(
+            env,
+            property_id_to_string(env, offering.property_id),
+            buyer.clone(),
+            shares as i128,
+            cost,
+        );
+    }
+
+    /// Close an offering once its window has ended or it sold out
+    ///
+    /// On success the proceeds and any unsold shares go to the owner. Otherwise
+    /// every offered share returns to the owner and buyers can claim refunds.
+    pub fn close(env: &Env, offering_id: u64) -> OfferingStatus {
+        let mut offering = OfferingStorage::get(env, offering_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if offering.status != OfferingStatus::Open {
+            panic_with_error!(env, ContractError::Closed);
+        }
+        let sold_out = offering.shares_sold == offering.terms.shares_offered;
+        if !sold_out && env.ledger().timestamp() < offering.terms.end_time {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+
+        let contract = env.current_contract_address();
+        let (status, shares_returned, proceeds) = if offering.shares_sold >= offering.terms.soft_cap
+        {
+            let proceeds = offering.terms.price_per_share * offering.shares_sold as i128;
+            if proceeds > 0 {
+                token::Client::new(env, &offering.terms.payment_token).transfer(
+                    &contract,
+                    &offering.owner,
+                    &proceeds,
+                );
+            }
+            (
+                OfferingStatus::Successful,
+                offering.terms.shares_offered - offering.shares_sold,
+                proceeds,
+            )
+        } else {
+            (OfferingStatus::Failed, offering.terms.shares_offered, 0)
+        };
+
+        if shares_returned > 0 {
+            ShareToken::transfer(
+                env,
+                offering.property_id,
+                &contract,
+                &offering.owner,
+                shares_returned,
+            );
+        }
+
+        offering.status = status.clone();
+        OfferingStorage::set(env, &offering);
+        OfferingStorage::clear_active(env, offering.property_id);
+
+        PropertyEvents::offering_closed(
+            env,
+            property_id_to_string(env, offering.property_id),
+            offering.id,
+            status == OfferingStatus::Successful,
+            offering.shares_sold as i128,
+            proceeds,
+        );
+
+        status
+    }
+
+    /// Claim purchased shares after a successful offering, or a refund after a failed one
+    pub fn claim(env: &Env, buyer: &Address, offering_id: u64) {
+        let offering = OfferingStorage::get(env, offering_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        let mut purchase = OfferingStorage::get_purchase(env, offering_id, buyer)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if purchase.claimed {
+            panic_with_error!(env, ContractError::Closed);
+        }
+
+        let contract = env.current_contract_address();
+        match offering.status {
+            OfferingStatus::Open => panic_with_error!(env, ContractError::TooEarly),
+            OfferingStatus::Successful => {
+                ShareToken::transfer(env, offering.property_id, &contract, buyer, purchase.shares);
+                ComplianceControl::start_lockup(env, offering.property_id, buyer);
+            }
+            OfferingStatus::Failed => {
+                token::Client::new(env, &offering.terms.payment_token).transfer(
+                    &contract,
+                    buyer,
+                    &purchase.amount_paid,
+                );
+                PropertyEvents::offering_refunded(
+                    env,
+                    property_id_to_string(env, offering.property_id),
+                    offering.id,
+                    buyer.clone(),
+                    purchase.amount_paid,
+                );
+            }
+        }
+
+        purchase.claimed = true;
+        OfferingStorage::set_purchase(env, offering_id, buyer, &purchase);
+    }
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Storage key types for the offering module
+#[derive(Clone)]
+#[contracttype]
+pub enum OfferingKey {
+    /// Offering state by offering ID
+    /// Storage: Persistent
+    Offering(u64),
+
+    /// Open offering ID for a property
+    /// Storage: Persistent
+    ActiveOffering(u64),
+
+    /// Purchase made by a buyer in an offering
+    /// Storage: Persistent
+    Purchase(u64, Address),
+
+    /// Counter used to generate offering IDs
+    /// Storage: Instance
+    OfferingCounter,
+}
+
+/// TTL bump amounts for offering storage
+pub mod offering_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
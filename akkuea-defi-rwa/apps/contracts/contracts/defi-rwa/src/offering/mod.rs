@@ -0,0 +1,11 @@
+// Offering module for DeFi RWA
+// This module provides the primary share offering flow: a property owner sells shares
+// for a payment token at a fixed price, with escrow and refunds when the soft cap is missed.
+
+mod control;
+mod keys;
+mod sale;
+
+pub use control::OfferingControl;
+pub use keys::{offering_bump, OfferingKey};
+pub use sale::{OfferingStatus, OfferingStorage, OfferingTerms, Purchase, ShareOffering};
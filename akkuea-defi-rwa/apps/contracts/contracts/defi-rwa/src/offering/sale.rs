@@ -0,0 +1,136 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use super::keys::{offering_bump, OfferingKey};
+use crate::errors::ContractError;
+
+/// Sale parameters chosen by the property owner
+#[derive(Clone)]
+#[contracttype]
+pub struct OfferingTerms {
+    /// Token accepted as payment (e.g., a USDC contract)
+    pub payment_token: Address,
+    /// Price of one share in payment token units
+    pub price_per_share: i128,
+    /// Number of shares put up for sale
+    pub shares_offered: u64,
+    /// Minimum number of shares per purchase
+    pub min_purchase: u64,
+    /// Maximum number of shares a single investor may buy
+    pub max_purchase: u64,
+    /// Minimum number of shares that must be sold for the sale to succeed
+    pub soft_cap: u64,
+    /// Timestamp at which the sale window closes
+    pub end_time: u64,
+}
+
+/// Lifecycle of an offering
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum OfferingStatus {
+    /// Accepting purchases
+    Open,
+    /// Soft cap reached, proceeds released to the owner
+    Successful,
+    /// Soft cap missed, buyers are refunded
+    Failed,
+}
+
+/// Primary share offering for a property
+#[derive(Clone)]
+#[contracttype]
+pub struct ShareOffering {
+    /// Unique offering identifier
+    pub id: u64,
+    /// Property whose shares are sold
+    pub property_id: u64,
+    /// Property owner receiving the proceeds
+    pub owner: Address,
+    /// Sale parameters
+    pub terms: OfferingTerms,
+    /// Shares bought so far
+    pub shares_sold: u64,
+    /// Timestamp at which the sale window opened
+    pub start_time: u64,
+    /// Current status
+    pub status: OfferingStatus,
+}
+
+/// Shares bought by an investor in an offering
+#[derive(Clone)]
+#[contracttype]
+pub struct Purchase {
+    /// Shares bought
+    pub shares: u64,
+    /// Payment held in escrow
+    pub amount_paid: i128,
+    /// Whether shares or refund were already claimed
+    pub claimed: bool,
+}
+
+/// Offering storage helpers
+pub struct OfferingStorage;
+
+impl OfferingStorage {
+    /// Generate the next offering ID
+    pub fn next_id(env: &Env) -> u64 {
+        let key = OfferingKey::OfferingCounter;
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        env.storage().instance().set(&key, &next);
+        next
+    }
+
+    /// Store offering
+    pub fn set(env: &Env, offering: &ShareOffering) {
+        let key = OfferingKey::Offering(offering.id);
+        env.storage().persistent().set(&key, offering);
+        env.storage().persistent().extend_ttl(
+            &key,
+            offering_bump::PERSISTENT_BUMP,
+            offering_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get offering by ID
+    pub fn get(env: &Env, offering_id: u64) -> Option<ShareOffering> {
+        let key = OfferingKey::Offering(offering_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Get the open offering of a property
+    pub fn get_active(env: &Env, property_id: u64) -> Option<u64> {
+        let key = OfferingKey::ActiveOffering(property_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Set the open offering of a property
+    pub fn set_active(env: &Env, property_id: u64, offering_id: u64) {
+        let key = OfferingKey::ActiveOffering(property_id);
+        env.storage().persistent().set(&key, &offering_id);
+    }
+
+    /// Clear the open offering of a property
+    pub fn clear_active(env: &Env, property_id: u64) {
+        let key = OfferingKey::ActiveOffering(property_id);
+        env.storage().persistent().remove(&key);
+    }
+
+    /// Get a buyer's purchase in an offering
+    pub fn get_purchase(env: &Env, offering_id: u64, buyer: &Address) -> Option<Purchase> {
+        let key = OfferingKey::Purchase(offering_id, buyer.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Store a buyer's purchase in an offering
+    pub fn set_purchase(env: &Env, offering_id: u64, buyer: &Address, purchase: &Purchase) {
+        let key = OfferingKey::Purchase(offering_id, buyer.clone());
+        env.storage().persistent().set(&key, purchase);
+        env.storage().persistent().extend_ttl(
+            &key,
+            offering_bump::PERSISTENT_BUMP,
+            offering_bump::PERSISTENT_BUMP,
+        );
+    }
+}
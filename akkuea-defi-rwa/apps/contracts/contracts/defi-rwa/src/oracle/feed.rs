@@ -0,0 +1,260 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Vec};
+
+use super::keys::{oracle_bump, OracleKey};
+use crate::access::{AdminControl, Role, RoleStorage};
+use crate::errors::ContractError;
+use crate::lending::PRECISION;
+use crate::storage::property::PropertyMetadata;
+
+/// Basis points denominator for deviation limits
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Asset whose value an oracle reports
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum PriceFeed {
+    /// Token contract; price is the value of one token unit (PRECISION scaled)
+    Asset(Address),
+    /// Tokenized property; price is the total property valuation in base units
+    Property(u64),
+}
+
+/// Aggregated price with its timestamp
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    /// Median of the fresh reports
+    pub price: i128,
+    /// Timestamp of the most recent report used
+    pub timestamp: u64,
+}
+
+/// Price reported by a single oracle
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceReport {
+    /// Reporting oracle
+    pub oracle: Address,
+    /// Reported price
+    pub price: i128,
+    /// Ledger timestamp of the report
+    pub timestamp: u64,
+}
+
+/// Oracle guard configuration
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OracleConfig {
+    /// Maximum age of a report, in seconds, for it to be used
+    pub max_age: u64,
+    /// Maximum change of a report against the current price, in basis points
+    pub max_deviation_bps: u32,
+}
+
+impl Default for OracleConfig {
+    /// Create default oracle configuration
+    /// Max age: 1 day, Max deviation: 20%
+    fn default() -> Self {
+        Self {
+            max_age: 86_400,
+            max_deviation_bps: 2_000,
+        }
+    }
+}
+
+/// Oracle storage helpers
+pub struct OracleStorage;
+
+impl OracleStorage {
+    /// Get the latest report of every oracle for a feed
+    pub fn get_reports(env: &Env, feed: &PriceFeed) -> Vec<PriceReport> {
+        let key = OracleKey::Reports(feed.clone());
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store the reports for a feed
+    pub fn set_reports(env: &Env, feed: &PriceFeed, reports: &Vec<PriceReport>) {
+        let key = OracleKey::Reports(feed.clone());
+        env.storage().persistent().set(&key, reports);
+        env.storage().persistent().extend_ttl(
+            &key,
+            oracle_bump::PERSISTENT_BUMP,
+            oracle_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the last aggregate accepted for a feed
+    pub fn get_accepted(env: &Env, feed: &PriceFeed) -> Option<PriceData> {
+        env.storage()
+            .persistent()
+            .get(&OracleKey::AcceptedPrice(feed.clone()))
+    }
+
+    /// Store the last aggregate accepted for a feed
+    pub fn set_accepted(env: &Env, feed: &PriceFeed, data: &PriceData) {
+        let key = OracleKey::AcceptedPrice(feed.clone());
+        env.storage().persistent().set(&key, data);
+        env.storage().persistent().extend_ttl(
+            &key,
+            oracle_bump::PERSISTENT_BUMP,
+            oracle_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get oracle configuration
+    pub fn get_config(env: &Env) -> OracleConfig {
+        env.storage()
+            .instance()
+            .get(&OracleKey::Config)
+            .unwrap_or_default()
+    }
+
+    /// Store oracle configuration
+    pub fn set_config(env: &Env, config: &OracleConfig) {
+        if config.max_age == 0 || config.max_deviation_bps == 0 {
+            panic_with_error!(env, ContractError::InvalidConfig);
+        }
+        env.storage().instance().set(&OracleKey::Config, config);
+    }
+}
+
+/// Price lookups used for collateral and debt valuation
+///
+/// Every oracle keeps one report per feed. The feed price is the median of
+/// the reports that are younger than `max_age` and come from accounts that
+/// still hold the oracle role. New reports are checked for deviation against
+/// that median, or against the last accepted median once every report has
+/// gone stale, so a lone reporter cannot reset the price after a quiet
+/// period. The first price of a token feed must be seeded by the admin.
+///
+/// Property feeds are anchored to the registry: every report must also stay
+/// within the deviation limit of the property's registry valuation, so the
+/// oracle price used for lending can never drift far from it. Moving a
+/// property's price further needs a new valuation signed off by verifiers.
+///
+/// Collateral and debt prices are the value of one unit in base currency,
+/// scaled by `PRECISION`.
+pub struct PriceOracle;
+
+impl PriceOracle {
+    /// Record an oracle's report for a feed
+    pub fn post(env: &Env, oracle: &Address, feed: &PriceFeed, price: i128) {
+        if price <= 0 {
+            panic_with_error!(env, ContractError::InvalidPrice);
+        }
+        let config = OracleStorage::get_config(env);
+        let current = Self::aggregate(env, feed).or_else(|| OracleStorage::get_accepted(env, feed));
+        match (feed, current) {
+            (_, Some(current)) => {
+                Self::require_within_deviation(env, &config, current.price, price);
+            }
+            (PriceFeed::Asset(_), None) if !AdminControl::is_admin(env, oracle) => {
+                panic_with_error!(env, ContractError::Unauthorized);
+            }
+            _ => {}
+        }
+        if let PriceFeed::Property(property_id) = feed {
+            let metadata = PropertyMetadata::load(env, *property_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+            Self::require_within_deviation(env, &config, metadata.valuation, price);
+        }
+
+        let report = PriceReport {
+            oracle: oracle.clone(),
+            price,
+            timestamp: env.ledger().timestamp(),
+        };
+        let mut reports = Vec::new(env);
+        for existing in OracleStorage::get_reports(env, feed).iter() {
+            if existing.oracle != *oracle {
+                reports.push_back(existing);
+            }
+        }
+        reports.push_back(report);
+        OracleStorage::set_reports(env, feed, &reports);
+        if let Some(accepted) = Self::aggregate(env, feed) {
+            OracleStorage::set_accepted(env, feed, &accepted);
+        }
+    }
+
+    /// Median of the fresh reports for a feed, if any
+    pub fn aggregate(env: &Env, feed: &PriceFeed) -> Option<PriceData> {
+        let now = env.ledger().timestamp();
+        let max_age = OracleStorage::get_config(env).max_age;
+
+        // Insertion sort keeps the fresh prices ordered for the median
+        let mut prices: Vec<i128> = Vec::new(env);
+        let mut timestamp = 0;
+        for report in OracleStorage::get_reports(env, feed).iter() {
+            if now.saturating_sub(report.timestamp) > max_age
+                || !Self::is_oracle(env, &report.oracle)
+            {
+                continue;
+            }
+            timestamp = timestamp.max(report.timestamp);
+            let mut position = prices.len();
+            for i in 0..prices.len() {
+                if report.price < prices.get(i).unwrap() {
+                    position = i;
+                    break;
+                }
+            }
+            prices.insert(position, report.price);
+        }
+
+        let count = prices.len();
+        if count == 0 {
+            return None;
+        }
+        let middle = count / 2;
+        let price = if count % 2 == 1 {
+            prices.get(middle).unwrap()
+        } else {
+            (prices.get(middle - 1).unwrap() + prices.get(middle).unwrap()) / 2
+        };
+        Some(PriceData { price, timestamp })
+    }
+
+    /// Fresh aggregated price for a feed
+    ///
+    /// # Panics
+    /// Panics if no report exists or every report is stale
+    pub fn fresh_price(env: &Env, feed: &PriceFeed) -> i128 {
+        match Self::aggregate(env, feed) {
+            Some(data) => data.price,
+            None if OracleStorage::get_reports(env, feed).is_empty() => {
+                panic_with_error!(env, ContractError::PriceNotAvailable)
+            }
+            None => panic_with_error!(env, ContractError::PriceStale),
+        }
+    }
+
+    /// Price of one unit of a token
+    pub fn asset_price(env: &Env, asset: &Address) -> i128 {
+        Self::fresh_price(env, &PriceFeed::Asset(asset.clone()))
+    }
+
+    /// Price of one share of a property, from its latest oracle valuation
+    /// (kept within the deviation limit of the registry valuation)
+    pub fn share_price(env: &Env, property_id: u64) -> i128 {
+        let metadata = PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        let valuation = Self::fresh_price(env, &PriceFeed::Property(property_id));
+        valuation * PRECISION / metadata.total_shares as i128
+    }
+
+    /// Panics if `price` deviates from `reference` by more than the configured limit
+    fn require_within_deviation(env: &Env, config: &OracleConfig, reference: i128, price: i128) {
+        let deviation = (price - reference).abs() * BPS_DENOMINATOR;
+        if deviation > reference * config.max_deviation_bps as i128 {
+            panic_with_error!(env, ContractError::PriceDeviationTooLarge);
+        }
+    }
+
+    fn is_oracle(env: &Env, account: &Address) -> bool {
+        AdminControl::is_admin(env, account) || RoleStorage::has_role(env, account, &Role::Oracle)
+    }
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::contracttype;
+
+use super::feed::PriceFeed;
+
+/// Storage key types for the oracle module
+#[derive(Clone)]
+#[contracttype]
+pub enum OracleKey {
+    /// Latest report of every oracle for a feed
+    /// Storage: Persistent
+    Reports(PriceFeed),
+
+    /// Last aggregate accepted for a feed, kept after its reports go stale
+    /// Storage: Persistent
+    AcceptedPrice(PriceFeed),
+
+    /// Staleness and deviation guards
+    /// Storage: Instance
+    Config,
+}
+
+/// TTL bump amounts for oracle storage
+pub mod oracle_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
@@ -0,0 +1,12 @@
+// Oracle module for DeFi RWA
+// This module stores prices posted by `Oracle` role accounts for pool assets and
+// tokenized properties, aggregates them by median with staleness and deviation
+// guards, and exposes them to the lending module for collateral valuation.
+
+mod feed;
+mod keys;
+
+pub use feed::{
+    OracleConfig, OracleStorage, PriceData, PriceFeed, PriceOracle, PriceReport, BPS_DENOMINATOR,
+};
+pub use keys::{oracle_bump, OracleKey};
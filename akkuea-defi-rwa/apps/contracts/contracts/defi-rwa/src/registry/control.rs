@@ -0,0 +1,259 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+
+use super::document::{DocumentType, PropertyDocument, MAX_DOCUMENTS};
+use super::valuation::{PendingValuation, PropertyAuditTrail, RegistryStorage, ValuationRecord};
+use crate::access::{AdminControl, Role, RoleStorage};
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, PropertyEvents};
+use crate::storage::property::PropertyMetadata;
+
+/// Property documents and valuation sign-off
+///
+/// The property owner, the admin and verifiers can attach documents and
+/// propose valuations; a proposed valuation only replaces the current one
+/// once enough distinct verifiers sign it off. Only one valuation can await
+/// sign-off at a time, and it is dropped once the admin or as many distinct
+/// verifiers as the quorum reject it.
+pub struct RegistryControl;
+
+impl RegistryControl {
+    /// Attach a document to a property, with the caller as issuer
+    pub fn add_document(
+        env: &Env,
+        issuer: &Address,
+        property_id: u64,
+        document_type: DocumentType,
+        content_hash: BytesN<32>,
+    ) {
+        let metadata = Self::load(env, property_id);
+        Self::require_attester(env, &metadata, issuer);
+
+        let mut documents = RegistryStorage::get_documents(env, property_id);
+        if documents.len() >= MAX_DOCUMENTS {
+            panic_with_error!(env, ContractError::CapExceeded);
+        }
+        documents.push_back(PropertyDocument {
+            document_type: document_type.clone(),
+            content_hash: content_hash.clone(),
+            issuer: issuer.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        RegistryStorage::set_documents(env, property_id, &documents);
+
+        PropertyEvents::document_added(
+            env,
+            property_id_to_string(env, property_id),
+            document_type,
+            content_hash,
+            issuer.clone(),
+        );
+    }
+
+    /// Propose a new valuation (property owner, admin or verifier)
+    ///
+    /// A referenced appraisal must already be attached to the property.
+    pub fn propose_valuation(
+        env: &Env,
+        proposer: &Address,
+        property_id: u64,
+        valuation: i128,
+        appraisal: Option<BytesN<32>>,
+    ) {
+        let metadata = Self::load(env, property_id);
+        Self::require_attester(env, &metadata, proposer);
+        if let Some(hash) = &appraisal {
+            let attached =
+                RegistryStorage::get_documents(env, property_id)
+                    .iter()
+                    .any(|document| {
+                        document.document_type == DocumentType::Appraisal
+                            && document.content_hash == *hash
+                    });
+            if !attached {
+                panic_with_error!(env, ContractError::NotFound);
+            }
+        }
+        Self::submit_valuation(env, proposer, property_id, valuation, appraisal);
+    }
+
+    /// Queue a valuation for sign-off
+    ///
+    /// # Panics
+    /// Panics with `AlreadyExists` while another valuation awaits sign-off
+    pub fn submit_valuation(
+        env: &Env,
+        proposer: &Address,
+        property_id: u64,
+        valuation: i128,
+        appraisal: Option<BytesN<32>>,
+    ) {
+        if valuation <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        if RegistryStorage::get_pending(env, property_id).is_some() {
+            panic_with_error!(env, ContractError::AlreadyExists);
+        }
+        RegistryStorage::set_pending(
+            env,
+            property_id,
+            &PendingValuation {
+                valuation,
+                appraisal,
+                proposed_by: proposer.clone(),
+                proposed_at: env.ledger().timestamp(),
+                approvals: Vec::new(env),
+            },
+        );
+
+        PropertyEvents::valuation_proposed(
+            env,
+            property_id_to_string(env, property_id),
+            valuation,
+            proposer.clone(),
+        );
+    }
+
+    /// Sign off the pending valuation (verifiers only)
+    ///
+    /// The verifier names the valuation they reviewed, so a sign-off cannot
+    /// land on a different proposal.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the valuation took effect
+    pub fn approve_valuation(
+        env: &Env,
+        verifier: &Address,
+        property_id: u64,
+        valuation: i128,
+    ) -> bool {
+        if !RoleStorage::has_role(env, verifier, &Role::Verifier) {
+            panic_with_error!(env, ContractError::Unauthorized);
+        }
+        let mut metadata = Self::load(env, property_id);
+        let mut pending = RegistryStorage::get_pending(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if pending.valuation != valuation {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+        if pending.approvals.contains(verifier)
+            || RegistryStorage::get_rejections(env, property_id).contains(verifier)
+        {
+            panic_with_error!(env, ContractError::AlreadyVoted);
+        }
+        pending.approvals.push_back(verifier.clone());
+
+        PropertyEvents::valuation_approved(
+            env,
+            property_id_to_string(env, property_id),
+            verifier.clone(),
+            pending.approvals.len(),
+        );
+
+        if pending.approvals.len() < RegistryStorage::get_quorum(env) {
+            RegistryStorage::set_pending(env, property_id, &pending);
+            return false;
+        }
+
+        let old_valuation = metadata.valuation;
+        metadata.valuation = pending.valuation;
+        metadata.save(env);
+        RegistryStorage::remove_pending(env, property_id);
+        RegistryStorage::push_history(
+            env,
+            property_id,
+            ValuationRecord {
+                valuation: pending.valuation,
+                appraisal: pending.appraisal,
+                proposed_by: pending.proposed_by,
+                approved_by: pending.approvals,
+                effective_at: env.ledger().timestamp(),
+            },
+        );
+
+        PropertyEvents::valuation_updated(
+            env,
+            property_id_to_string(env, property_id),
+            old_valuation,
+            pending.valuation,
+        );
+        true
+    }
+
+    /// Reject the pending valuation (admin or verifier)
+    ///
+    /// The admin drops it outright; a verifier's rejection counts as a vote,
+    /// and the valuation is dropped once the rejections reach the quorum.
+    ///
+    /// # Returns
+    /// * `bool` - Whether the valuation was dropped
+    pub fn reject_valuation(env: &Env, caller: &Address, property_id: u64) -> bool {
+        let pending = RegistryStorage::get_pending(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+
+        if !AdminControl::is_admin(env, caller) {
+            let mut rejections = RegistryStorage::get_rejections(env, property_id);
+            if pending.approvals.contains(caller) || rejections.contains(caller) {
+                panic_with_error!(env, ContractError::AlreadyVoted);
+            }
+            rejections.push_back(caller.clone());
+            if rejections.len() < RegistryStorage::get_quorum(env) {
+                RegistryStorage::set_rejections(env, property_id, &rejections);
+                return false;
+            }
+        }
+        RegistryStorage::remove_pending(env, property_id);
+
+        PropertyEvents::valuation_rejected(
+            env,
+            property_id_to_string(env, property_id),
+            caller.clone(),
+        );
+        true
+    }
+
+    /// Record the valuation declared at registration as the first history entry
+    pub fn record_initial_valuation(env: &Env, metadata: &PropertyMetadata) {
+        RegistryStorage::push_history(
+            env,
+            metadata.property_id,
+            ValuationRecord {
+                valuation: metadata.valuation,
+                appraisal: None,
+                proposed_by: metadata.owner.clone(),
+                approved_by: Vec::new(env),
+                effective_at: metadata.created_at,
+            },
+        );
+    }
+
+    /// Set how many distinct verifiers must sign off a valuation
+    pub fn set_quorum(env: &Env, quorum: u32) {
+        if quorum == 0 {
+            panic_with_error!(env, ContractError::InvalidConfig);
+        }
+        RegistryStorage::set_quorum(env, quorum);
+    }
+
+    /// Documents and valuation history of a property
+    pub fn audit_trail(env: &Env, property_id: u64) -> PropertyAuditTrail {
+        Self::load(env, property_id);
+        PropertyAuditTrail {
+            documents: RegistryStorage::get_documents(env, property_id),
+            valuations: RegistryStorage::get_history(env, property_id),
+        }
+    }
+
+    fn load(env: &Env, property_id: u64) -> PropertyMetadata {
+        PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound))
+    }
+
+    fn require_attester(env: &Env, metadata: &PropertyMetadata, caller: &Address) {
+        let allowed = metadata.owner == *caller
+            || AdminControl::is_admin(env, caller)
+            || RoleStorage::has_role(env, caller, &Role::Verifier);
+        if !allowed {
+            panic_with_error!(env, ContractError::Unauthorized);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// Most documents a single property can carry
+pub const MAX_DOCUMENTS: u32 = 100;
+
+/// Kind of evidence a document provides
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DocumentType {
+    /// Proof of legal ownership
+    TitleDeed,
+    /// Independent appraisal report backing a valuation
+    Appraisal,
+    /// Insurance policy covering the property
+    Insurance,
+    /// Physical inspection report
+    Inspection,
+}
+
+/// Document attached to a property
+///
+/// Only the hash of the content is stored on-chain; the document itself lives
+/// off-chain and can be checked against it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PropertyDocument {
+    /// Kind of document
+    pub document_type: DocumentType,
+    /// SHA-256 hash of the document content
+    pub content_hash: BytesN<32>,
+    /// Account that attached the document
+    pub issuer: Address,
+    /// Ledger timestamp when the document was attached
+    pub timestamp: u64,
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::contracttype;
+
+/// Storage key types for the registry module
+#[derive(Clone)]
+#[contracttype]
+pub enum RegistryKey {
+    /// Documents attached to a property
+    /// Storage: Persistent
+    Documents(u64),
+
+    /// Valuations that took effect for a property, oldest first
+    /// Storage: Persistent
+    ValuationHistory(u64),
+
+    /// Valuation of a property awaiting verifier sign-off
+    /// Storage: Persistent
+    PendingValuation(u64),
+
+    /// Verifiers that rejected the pending valuation of a property
+    /// Storage: Persistent
+    ValuationRejections(u64),
+
+    /// Number of distinct verifiers that must sign off a valuation
+    /// Storage: Instance
+    ValuationQuorum,
+}
+
+/// TTL bump amounts for registry storage
+pub mod registry_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
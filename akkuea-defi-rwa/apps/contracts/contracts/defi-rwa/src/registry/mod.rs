@@ -0,0 +1,13 @@
+// Registry module for DeFi RWA
+// This module keeps the evidence behind each property: its documents (title deed, appraisals,
+// insurance, inspections), its valuation history and the verifier sign-off on new valuations.
+
+mod control;
+mod document;
+mod keys;
+mod valuation;
+
+pub use control::RegistryControl;
+pub use document::{DocumentType, PropertyDocument, MAX_DOCUMENTS};
+pub use keys::{registry_bump, RegistryKey};
+pub use valuation::{PendingValuation, PropertyAuditTrail, RegistryStorage, ValuationRecord};
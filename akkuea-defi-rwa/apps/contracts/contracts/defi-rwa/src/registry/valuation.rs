@@ -0,0 +1,153 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use super::document::PropertyDocument;
+use super::keys::{registry_bump, RegistryKey};
+
+/// Valuation that took effect for a property
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ValuationRecord {
+    /// Total valuation in base currency units
+    pub valuation: i128,
+    /// Hash of the appraisal document backing the valuation, if any
+    pub appraisal: Option<BytesN<32>>,
+    /// Account that proposed the valuation
+    pub proposed_by: Address,
+    /// Verifiers that signed it off (empty for the valuation declared at registration)
+    pub approved_by: Vec<Address>,
+    /// Ledger timestamp when the valuation took effect
+    pub effective_at: u64,
+}
+
+/// Valuation awaiting verifier sign-off
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingValuation {
+    /// Proposed total valuation in base currency units
+    pub valuation: i128,
+    /// Hash of the appraisal document backing the valuation, if any
+    pub appraisal: Option<BytesN<32>>,
+    /// Account that proposed the valuation
+    pub proposed_by: Address,
+    /// Ledger timestamp of the proposal
+    pub proposed_at: u64,
+    /// Verifiers that signed it off so far
+    pub approvals: Vec<Address>,
+}
+
+/// Everything on record about a property's documents and valuations
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PropertyAuditTrail {
+    /// Attached documents, oldest first
+    pub documents: Vec<PropertyDocument>,
+    /// Valuations that took effect, oldest first
+    pub valuations: Vec<ValuationRecord>,
+}
+
+/// Registry storage helpers
+pub struct RegistryStorage;
+
+impl RegistryStorage {
+    /// Get the documents attached to a property
+    pub fn get_documents(env: &Env, property_id: u64) -> Vec<PropertyDocument> {
+        env.storage()
+            .persistent()
+            .get(&RegistryKey::Documents(property_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Replace the documents attached to a property
+    pub fn set_documents(env: &Env, property_id: u64, documents: &Vec<PropertyDocument>) {
+        let key = RegistryKey::Documents(property_id);
+        env.storage().persistent().set(&key, documents);
+        env.storage().persistent().extend_ttl(
+            &key,
+            registry_bump::PERSISTENT_BUMP,
+            registry_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the valuation history of a property
+    pub fn get_history(env: &Env, property_id: u64) -> Vec<ValuationRecord> {
+        env.storage()
+            .persistent()
+            .get(&RegistryKey::ValuationHistory(property_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Append a valuation to the history of a property
+    pub fn push_history(env: &Env, property_id: u64, record: ValuationRecord) {
+        let key = RegistryKey::ValuationHistory(property_id);
+        let mut history = Self::get_history(env, property_id);
+        history.push_back(record);
+        env.storage().persistent().set(&key, &history);
+        env.storage().persistent().extend_ttl(
+            &key,
+            registry_bump::PERSISTENT_BUMP,
+            registry_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get the valuation awaiting sign-off
+    pub fn get_pending(env: &Env, property_id: u64) -> Option<PendingValuation> {
+        env.storage()
+            .persistent()
+            .get(&RegistryKey::PendingValuation(property_id))
+    }
+
+    /// Store the valuation awaiting sign-off
+    pub fn set_pending(env: &Env, property_id: u64, pending: &PendingValuation) {
+        let key = RegistryKey::PendingValuation(property_id);
+        env.storage().persistent().set(&key, pending);
+        env.storage().persistent().extend_ttl(
+            &key,
+            registry_bump::PERSISTENT_BUMP,
+            registry_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Drop the valuation awaiting sign-off along with its rejections
+    pub fn remove_pending(env: &Env, property_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&RegistryKey::PendingValuation(property_id));
+        env.storage()
+            .persistent()
+            .remove(&RegistryKey::ValuationRejections(property_id));
+    }
+
+    /// Get the verifiers that rejected the valuation awaiting sign-off
+    pub fn get_rejections(env: &Env, property_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&RegistryKey::ValuationRejections(property_id))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Store the verifiers that rejected the valuation awaiting sign-off
+    pub fn set_rejections(env: &Env, property_id: u64, rejections: &Vec<Address>) {
+        let key = RegistryKey::ValuationRejections(property_id);
+        env.storage().persistent().set(&key, rejections);
+        env.storage().persistent().extend_ttl(
+            &key,
+            registry_bump::PERSISTENT_BUMP,
+            registry_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Number of distinct verifiers that must sign off a valuation (defaults to 1)
+    pub fn get_quorum(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&RegistryKey::ValuationQuorum)
+            .unwrap_or(1)
+    }
+
+    /// Set the number of distinct verifiers that must sign off a valuation
+    pub fn set_quorum(env: &Env, quorum: u32) {
+        env.storage()
+            .instance()
+            .set(&RegistryKey::ValuationQuorum, &quorum);
+    }
+}
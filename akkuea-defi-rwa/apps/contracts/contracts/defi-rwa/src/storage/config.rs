@@ -1,6 +1,7 @@
-use soroban_sdk::{contracttype, Address, Env, String};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
 
 use super::keys::StorageKey;
+use crate::errors::ContractError;
 
 /// Token configuration for the property tokenization contract
 ///
@@ -139,7 +140,9 @@ pub fn get_property_counter(env: &Env) -> u64 {
 #[allow(dead_code)]
 pub fn increment_property_counter(env: &Env) -> u64 {
     let current = get_property_counter(env);
-    let next = current.checked_add(1).expect("Property counter overflow");
+    let next = current
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
     let key = StorageKey::PropertyCounter;
     env.storage().instance().set(&key, &next);
     next
@@ -20,6 +20,18 @@ pub enum StorageKey {
     /// Key: ShareBalance(property_id, owner_address)
     ShareBalance(u64, Address),
 
+    /// Share allowance granted by an owner to a spender for a property
+    /// Key: ShareAllowance(property_id, owner_address, spender_address)
+    ShareAllowance(u64, Address, Address),
+
+    /// Number of balance checkpoints recorded for an account
+    /// Key: CheckpointCount(property_id, owner_address)
+    CheckpointCount(u64, Address),
+
+    /// Balance checkpoint of an account, used for voting snapshots
+    /// Key: Checkpoint(property_id, owner_address, index)
+    Checkpoint(u64, Address, u32),
+
     /// Total shares issued for a property
     /// Key: TotalShares(property_id)
     TotalShares(u64),
@@ -31,6 +43,10 @@ pub enum StorageKey {
     /// Property counter to generate unique property IDs
     /// Single instance per contract
     PropertyCounter,
+
+    /// Pause flags scoped to a single property, kept in persistent storage
+    /// Key: PropertyPaused(property_id)
+    PropertyPaused(u64),
 }
 
 impl StorageKey {
@@ -41,9 +57,17 @@ impl StorageKey {
             StorageKey::TokenConfig => Symbol::new(&soroban_sdk::Env::default(), "TknCfg"),
             StorageKey::Property(_) => Symbol::new(&soroban_sdk::Env::default(), "Prop"),
             StorageKey::ShareBalance(_, _) => Symbol::new(&soroban_sdk::Env::default(), "Share"),
+            StorageKey::ShareAllowance(_, _, _) => {
+                Symbol::new(&soroban_sdk::Env::default(), "Allow")
+            }
+            StorageKey::CheckpointCount(_, _) => {
+                Symbol::new(&soroban_sdk::Env::default(), "ChkCnt")
+            }
+            StorageKey::Checkpoint(_, _, _) => Symbol::new(&soroban_sdk::Env::default(), "Chkpt"),
             StorageKey::TotalShares(_) => Symbol::new(&soroban_sdk::Env::default(), "TotShrs"),
             StorageKey::Admin => Symbol::new(&soroban_sdk::Env::default(), "Admin"),
             StorageKey::PropertyCounter => Symbol::new(&soroban_sdk::Env::default(), "PropCnt"),
+            StorageKey::PropertyPaused(_) => Symbol::new(&soroban_sdk::Env::default(), "PropPsd"),
         }
     }
 }
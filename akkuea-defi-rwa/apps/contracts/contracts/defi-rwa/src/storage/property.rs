@@ -35,6 +35,9 @@ pub struct PropertyMetadata {
 
     /// Whether the property is active for trading
     pub is_active: bool,
+
+    /// Whether the property has been verified by a `Verifier`
+    pub is_verified: bool,
 }
 
 impl PropertyMetadata {
@@ -70,6 +73,7 @@ impl PropertyMetadata {
             total_shares,
             created_at,
             is_active: true,
+            is_verified: false,
         }
     }
 
@@ -105,6 +109,15 @@ impl PropertyMetadata {
         self.save(env);
     }
 
+    /// Marks the property as verified
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    pub fn set_verified(&mut self, env: &Env) {
+        self.is_verified = true;
+        self.save(env);
+    }
+
     /// Removes property metadata from storage
     ///
     /// # Arguments
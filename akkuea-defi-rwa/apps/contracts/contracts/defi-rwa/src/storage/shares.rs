@@ -1,6 +1,7 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, IntoVal, Val};
 
 use super::keys::StorageKey;
+use crate::errors::ContractError;
 
 /// Share balance tracking for property ownership
 ///
@@ -42,6 +43,125 @@ pub fn set_balance(env: &Env, property_id: u64, owner: &Address, balance: u64) {
     } else {
         env.storage().instance().set(&key, &balance);
     }
+
+    record_checkpoint(env, property_id, owner, balance);
+}
+
+/// Balance of an account as of the end of a ledger
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceCheckpoint {
+    /// Ledger sequence of the last balance change in that ledger
+    pub ledger: u32,
+
+    /// Balance after the change
+    pub balance: u64,
+}
+
+/// TTL bump for balance checkpoints (~300 days)
+const CHECKPOINT_BUMP: u32 = 2592000;
+
+/// Records an account's balance for the current ledger
+///
+/// Checkpoints are stored one entry each, so an account's history can grow
+/// without any single entry growing. Several changes within one ledger
+/// collapse into a single checkpoint holding the final balance. The contract's
+/// own escrow balance is not recorded since it never votes.
+fn record_checkpoint(env: &Env, property_id: u64, owner: &Address, balance: u64) {
+    if *owner == env.current_contract_address() {
+        return;
+    }
+    let ledger = env.ledger().sequence();
+    let count = get_checkpoint_count(env, property_id, owner);
+
+    let last = if count > 0 {
+        get_checkpoint(env, property_id, owner, count - 1)
+    } else {
+        None
+    };
+
+    let index = match last {
+        Some(last) if last.ledger == ledger => count - 1,
+        _ => {
+            let count_key = StorageKey::CheckpointCount(property_id, owner.clone());
+            env.storage().persistent().set(&count_key, &(count + 1));
+            env.storage()
+                .persistent()
+                .extend_ttl(&count_key, CHECKPOINT_BUMP, CHECKPOINT_BUMP);
+            count
+        }
+    };
+
+    let key = StorageKey::Checkpoint(property_id, owner.clone(), index);
+    env.storage()
+        .persistent()
+        .set(&key, &BalanceCheckpoint { ledger, balance });
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, CHECKPOINT_BUMP, CHECKPOINT_BUMP);
+}
+
+/// Number of checkpoints recorded for an account
+fn get_checkpoint_count(env: &Env, property_id: u64, owner: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::CheckpointCount(property_id, owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Checkpoint of an account by index
+fn get_checkpoint(
+    env: &Env,
+    property_id: u64,
+    owner: &Address,
+    index: u32,
+) -> Option<BalanceCheckpoint> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Checkpoint(property_id, owner.clone(), index))
+}
+
+/// Gets the share balance of an owner as of the end of a ledger
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `property_id` - ID of the property
+/// * `owner` - Address of the share owner
+/// * `ledger` - Ledger sequence to look up
+///
+/// # Returns
+/// * `u64` - Number of shares owned at the end of `ledger`
+pub fn get_balance_at(env: &Env, property_id: u64, owner: &Address, ledger: u32) -> u64 {
+    let load = |index: u32| {
+        get_checkpoint(env, property_id, owner, index)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound))
+    };
+    let count = get_checkpoint_count(env, property_id, owner);
+    if count == 0 {
+        return 0;
+    }
+
+    // Most lookups concern holders that have not moved since the snapshot
+    let last = load(count - 1);
+    if last.ledger <= ledger {
+        return last.balance;
+    }
+
+    // Binary search for the last checkpoint at or before `ledger`
+    let (mut low, mut high) = (0, count - 1);
+    while low < high {
+        let mid = (low + high) / 2;
+        if load(mid).ledger <= ledger {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == 0 {
+        0
+    } else {
+        load(low - 1).balance
+    }
 }
 
 /// Increases the share balance for an owner
@@ -57,7 +177,9 @@ pub fn set_balance(env: &Env, property_id: u64, owner: &Address, balance: u64) {
 #[allow(dead_code)]
 pub fn increase_balance(env: &Env, property_id: u64, owner: &Address, amount: u64) {
     let current = get_balance(env, property_id, owner);
-    let new_balance = current.checked_add(amount).expect("Share balance overflow");
+    let new_balance = current
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
     set_balance(env, property_id, owner, new_balance);
 }
 
@@ -76,7 +198,7 @@ pub fn decrease_balance(env: &Env, property_id: u64, owner: &Address, amount: u6
     let current = get_balance(env, property_id, owner);
     let new_balance = current
         .checked_sub(amount)
-        .expect("Insufficient share balance");
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InsufficientShares));
     set_balance(env, property_id, owner, new_balance);
 }
 
@@ -122,3 +244,154 @@ pub fn transfer_shares(env: &Env, property_id: u64, from: &Address, to: &Address
     decrease_balance(env, property_id, from, amount);
     increase_balance(env, property_id, to, amount);
 }
+
+/// Allowance granted by a share owner to a spender
+///
+/// Mirrors the SEP-41 allowance model: the allowance is only valid up to and
+/// including `expiration_ledger`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareAllowance {
+    /// Number of shares the spender may transfer
+    pub amount: i128,
+
+    /// Last ledger sequence at which the allowance is valid
+    pub expiration_ledger: u32,
+}
+
+/// Reads the active allowance stored under `key`
+///
+/// Shared by every SEP-41 style token of the contract (property shares and
+/// lending receipts), which only differ in their allowance key.
+///
+/// # Returns
+/// * `i128` - Allowed amount (0 if none or expired)
+pub fn read_allowance<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> i128 {
+    match env.storage().temporary().get::<_, ShareAllowance>(key) {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => {
+            allowance.amount
+        }
+        _ => 0,
+    }
+}
+
+/// Writes an allowance under `key`
+///
+/// # Storage Optimization
+/// Allowances live in temporary storage and expire with their ledger
+///
+/// # Panics
+/// Panics if a non-zero allowance expires in the past
+pub fn write_allowance<K: IntoVal<Env, Val>>(
+    env: &Env,
+    key: &K,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, ContractError::InvalidDeadline);
+    }
+
+    let allowance = ShareAllowance {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(key, &allowance);
+
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(key, live_for, live_for);
+    }
+}
+
+/// Consumes part of the allowance stored under `key`
+///
+/// # Panics
+/// Panics if `amount` is negative or the allowance is lower than `amount`
+pub fn consume_allowance<K: IntoVal<Env, Val>>(env: &Env, key: &K, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if amount == 0 {
+        return;
+    }
+    match env.storage().temporary().get::<_, ShareAllowance>(key) {
+        Some(allowance)
+            if allowance.expiration_ledger >= env.ledger().sequence()
+                && allowance.amount >= amount =>
+        {
+            write_allowance(
+                env,
+                key,
+                allowance.amount - amount,
+                allowance.expiration_ledger,
+            );
+        }
+        _ => panic_with_error!(env, ContractError::InsufficientAllowance),
+    }
+}
+
+/// Gets the active allowance for a spender on a property
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `property_id` - ID of the property
+/// * `owner` - Address of the share owner
+/// * `spender` - Address allowed to spend the shares
+///
+/// # Returns
+/// * `i128` - Allowed amount (0 if none or expired)
+pub fn get_allowance(env: &Env, property_id: u64, owner: &Address, spender: &Address) -> i128 {
+    read_allowance(
+        env,
+        &StorageKey::ShareAllowance(property_id, owner.clone(), spender.clone()),
+    )
+}
+
+/// Sets the allowance for a spender on a property
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `property_id` - ID of the property
+/// * `owner` - Address of the share owner
+/// * `spender` - Address allowed to spend the shares
+/// * `amount` - Allowed amount
+/// * `expiration_ledger` - Last ledger at which the allowance is valid
+///
+/// # Panics
+/// Panics if a non-zero allowance expires in the past
+pub fn set_allowance(
+    env: &Env,
+    property_id: u64,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    write_allowance(
+        env,
+        &StorageKey::ShareAllowance(property_id, owner.clone(), spender.clone()),
+        amount,
+        expiration_ledger,
+    );
+}
+
+/// Consumes part of a spender's allowance
+///
+/// # Panics
+/// Panics if the allowance is lower than `amount`
+pub fn spend_allowance(
+    env: &Env,
+    property_id: u64,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+) {
+    consume_allowance(
+        env,
+        &StorageKey::ShareAllowance(property_id, owner.clone(), spender.clone()),
+        amount,
+    );
+}
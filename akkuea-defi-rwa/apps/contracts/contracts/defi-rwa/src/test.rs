@@ -1,6 +1,11 @@
 use super::access::{AdminControl, PauseControl};
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Events, Address, Env, String};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Bytes, BytesN, Env, String,
+};
 
 // Created this contract just for testing storage
 #[contract]
@@ -120,6 +125,8 @@ fn test_store_and_retrieve_borrow_position() {
         index_at_borrow: PRECISION,
         collateral_amount: 1_000_000_000,
         collateral_asset: collateral_asset.clone(),
+        collateral_property: None,
+        collateral_pool: None,
         borrowed_at: 1700000000,
     };
 
@@ -152,7 +159,7 @@ fn test_store_and_retrieve_borrow_position() {
 
 #[test]
 fn test_interest_rate_calculation() {
-    let model = InterestRateModel::default();
+    let model = JumpRateModel::default();
 
     // Test at 0% utilization
     let rate_0 = model.calculate_borrow_rate(0);
@@ -423,7 +430,7 @@ fn test_admin_initialization() {
 }
 
 #[test]
-#[should_panic(expected = "Caller not admin")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_require_admin_fails() {
     let (contract_id, env) = setup();
     let admin = Address::generate(&env);
@@ -565,3 +572,3178 @@ fn test_borrow_event() {
     let events = env.events().all();
     assert_eq!(events.len(), 1);
 }
+
+// =========================================================================
+// Property Registry Entry Points
+// =========================================================================
+
+fn setup_registry() -> (Env, PropertyTokenContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(PropertyTokenContract, ());
+    let client = PropertyTokenContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "Akkuea Property Share"),
+        &String::from_str(&env, "APS"),
+        &MIN_DELAY,
+    );
+    (env, client, admin)
+}
+
+/// Minimum timelock delay test contracts are initialized with
+const MIN_DELAY: u64 = 1;
+
+/// Queues an operation and executes it as soon as its delay has passed
+fn run_operation(
+    client: &PropertyTokenContractClient,
+    admin: &Address,
+    operation: TimelockOperation,
+) {
+    let operation_id = client.queue_operation(admin, &operation);
+    let eta = client.get_queued_operation(&operation_id).unwrap().eta;
+    client.env.ledger().set_timestamp(eta);
+    client.execute_operation(admin, &operation_id);
+}
+
+/// Grants a role through the timelock
+fn grant_role(
+    client: &PropertyTokenContractClient,
+    admin: &Address,
+    account: &Address,
+    role: Role,
+) {
+    run_operation(
+        client,
+        admin,
+        TimelockOperation::GrantRole(account.clone(), role),
+    );
+}
+
+/// Applies the same delay to every kind of timelocked operation
+fn set_timelock_delays(client: &PropertyTokenContractClient, admin: &Address, delay: u64) {
+    run_operation(
+        client,
+        admin,
+        TimelockOperation::SetConfig(TimelockConfig::uniform(delay)),
+    );
+}
+
+fn register_test_property(
+    env: &Env,
+    client: &PropertyTokenContractClient,
+    owner: &Address,
+    total_shares: u64,
+) -> u64 {
+    client.register_property(
+        &client.get_admin().unwrap(),
+        owner,
+        &String::from_str(env, "Beach House"),
+        &String::from_str(env, "Beachfront property"),
+        &String::from_str(env, "Miami, FL"),
+        &10_000_000_000_000,
+        &total_shares,
+    )
+}
+
+#[test]
+fn test_register_and_get_property() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+
+    let property_id = register_test_property(&env, &client, &owner, 100_000);
+    assert_eq!(property_id, 1);
+
+    let property = client.get_property(&property_id);
+    assert_eq!(property.owner, owner);
+    assert_eq!(property.total_shares, 100_000);
+    assert!(property.is_active);
+    assert!(!property.is_verified);
+
+    let total = env.as_contract(&client.address, || {
+        shares::get_total_shares(&env, property_id)
+    });
+    let balance = env.as_contract(&client.address, || {
+        shares::get_balance(&env, property_id, &owner)
+    });
+    assert_eq!(total, 100_000);
+    assert_eq!(balance, 100_000);
+
+    let second_id = register_test_property(&env, &client, &owner, 10);
+    assert_eq!(second_id, 2);
+}
+
+#[test]
+fn test_verify_property_by_verifier() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    grant_role(&client, &admin, &verifier, Role::Verifier);
+    assert!(client.has_role(&verifier, &Role::Verifier));
+
+    client.verify_property(&verifier, &property_id);
+    assert_eq!(env.events().all().len(), 1);
+    assert!(client.get_property(&property_id).is_verified);
+}
+
+#[test]
+fn test_verify_property_requires_verifier_role() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    assert_eq!(
+        client.try_verify_property(&owner, &property_id),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+}
+
+#[test]
+fn test_deactivate_property() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    client.deactivate_property(&admin, &property_id);
+    assert!(!client.get_property(&property_id).is_active);
+}
+
+#[test]
+fn test_register_property_when_paused() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_register_property(
+            &admin,
+            &owner,
+            &String::from_str(&env, "Beach House"),
+            &String::from_str(&env, "Ocean view"),
+            &String::from_str(&env, "Miami"),
+            &1_000_000,
+            &1_000,
+        ),
+        Err(Ok(ContractError::ContractPaused.into()))
+    );
+}
+
+#[test]
+fn test_register_property_requires_admin_or_verifier() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let register = |registrar: &Address| {
+        client.try_register_property(
+            registrar,
+            &owner,
+            &String::from_str(&env, "Beach House"),
+            &String::from_str(&env, "Ocean view"),
+            &String::from_str(&env, "Miami"),
+            &1_000_000,
+            &1_000,
+        )
+    };
+
+    assert_eq!(
+        register(&Address::generate(&env)),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    grant_role(&client, &admin, &verifier, Role::Verifier);
+    assert_eq!(register(&verifier), Ok(Ok(1)));
+    assert_eq!(client.balance(&1, &owner), 1_000);
+    assert_eq!(
+        client.register_property(
+            &admin,
+            &admin,
+            &String::from_str(&env, "Loft"),
+            &String::from_str(&env, "City loft"),
+            &String::from_str(&env, "Lisbon"),
+            &1_000_000,
+            &1_000,
+        ),
+        2
+    );
+}
+
+// =========================================================================
+// Documents and Valuations
+// =========================================================================
+
+#[test]
+fn test_valuation_takes_effect_after_quorum_of_verifiers() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let first_verifier = Address::generate(&env);
+    let second_verifier = Address::generate(&env);
+    grant_role(&client, &admin, &first_verifier, Role::Verifier);
+    grant_role(&client, &admin, &second_verifier, Role::Verifier);
+    client.set_valuation_quorum(&admin, &2);
+
+    let appraisal = BytesN::from_array(&env, &[1; 32]);
+    client.add_document(&owner, &property_id, &DocumentType::Appraisal, &appraisal);
+    client.propose_valuation(
+        &owner,
+        &property_id,
+        &20_000_000_000_000,
+        &Some(appraisal.clone()),
+    );
+
+    // A sign-off names the valuation it reviewed and pending ones are not replaced
+    assert_eq!(
+        client.try_propose_valuation(&owner, &property_id, &1, &None),
+        Err(Ok(ContractError::AlreadyExists.into()))
+    );
+    assert_eq!(
+        client.try_approve_valuation(&first_verifier, &property_id, &1),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+
+    let proposed = 20_000_000_000_000;
+    assert!(!client.approve_valuation(&first_verifier, &property_id, &proposed));
+    assert_eq!(
+        client.try_approve_valuation(&first_verifier, &property_id, &proposed),
+        Err(Ok(ContractError::AlreadyVoted.into()))
+    );
+    assert_eq!(
+        client.try_approve_valuation(&admin, &property_id, &proposed),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    assert_eq!(
+        client.get_property(&property_id).valuation,
+        10_000_000_000_000
+    );
+
+    assert!(client.approve_valuation(&second_verifier, &property_id, &proposed));
+    assert_eq!(
+        client.get_property(&property_id).valuation,
+        20_000_000_000_000
+    );
+    assert_eq!(client.get_pending_valuation(&property_id), None);
+
+    let history = client.get_valuation_history(&property_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get_unchecked(0).valuation, 10_000_000_000_000);
+    let record = history.get_unchecked(1);
+    assert_eq!(record.appraisal, Some(appraisal));
+    assert_eq!(record.proposed_by, owner);
+    assert_eq!(
+        record.approved_by,
+        vec![&env, first_verifier, second_verifier]
+    );
+}
+
+#[test]
+fn test_property_audit_trail() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let verifier = Address::generate(&env);
+    grant_role(&client, &admin, &verifier, Role::Verifier);
+
+    let deed = BytesN::from_array(&env, &[2; 32]);
+    assert_eq!(
+        client.try_add_document(
+            &Address::generate(&env),
+            &property_id,
+            &DocumentType::TitleDeed,
+            &deed
+        ),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    client.add_document(&owner, &property_id, &DocumentType::TitleDeed, &deed);
+    env.ledger().set_timestamp(1_000);
+    let inspection = BytesN::from_array(&env, &[3; 32]);
+    client.add_document(
+        &verifier,
+        &property_id,
+        &DocumentType::Inspection,
+        &inspection,
+    );
+
+    // Valuations can only cite appraisals attached to the property
+    assert_eq!(
+        client.try_propose_valuation(&owner, &property_id, &5_000, &Some(deed)),
+        Err(Ok(ContractError::NotFound.into()))
+    );
+    client.propose_valuation(&verifier, &property_id, &5_000, &None);
+
+    let trail = client.get_audit_trail(&property_id);
+    assert_eq!(trail.documents.len(), 2);
+    let document = trail.documents.get_unchecked(1);
+    assert_eq!(document.document_type, DocumentType::Inspection);
+    assert_eq!(document.content_hash, inspection);
+    assert_eq!(document.issuer, verifier);
+    assert_eq!(document.timestamp, 1_000);
+    assert_eq!(trail.valuations.len(), 1);
+    assert_eq!(
+        client
+            .get_pending_valuation(&property_id)
+            .unwrap()
+            .valuation,
+        5_000
+    );
+
+    assert!(client.reject_valuation(&verifier, &property_id));
+    assert_eq!(client.get_pending_valuation(&property_id), None);
+    assert_eq!(
+        client.try_approve_valuation(&verifier, &property_id, &5_000),
+        Err(Ok(ContractError::NotFound.into()))
+    );
+}
+
+#[test]
+fn test_valuation_rejection_needs_quorum_of_verifiers() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let first_verifier = Address::generate(&env);
+    let second_verifier = Address::generate(&env);
+    grant_role(&client, &admin, &first_verifier, Role::Verifier);
+    grant_role(&client, &admin, &second_verifier, Role::Verifier);
+    client.set_valuation_quorum(&admin, &2);
+    client.propose_valuation(&owner, &property_id, &5_000, &None);
+
+    // One verifier's rejection is only a vote
+    assert!(!client.reject_valuation(&first_verifier, &property_id));
+    assert!(client.get_pending_valuation(&property_id).is_some());
+    assert_eq!(
+        client.get_valuation_rejections(&property_id),
+        vec![&env, first_verifier.clone()]
+    );
+    assert_eq!(
+        client.try_approve_valuation(&first_verifier, &property_id, &5_000),
+        Err(Ok(ContractError::AlreadyVoted.into()))
+    );
+    assert_eq!(
+        client.try_reject_valuation(&first_verifier, &property_id),
+        Err(Ok(ContractError::AlreadyVoted.into()))
+    );
+
+    assert!(client.reject_valuation(&second_verifier, &property_id));
+    assert_eq!(client.get_pending_valuation(&property_id), None);
+    assert!(client.get_valuation_rejections(&property_id).is_empty());
+
+    // The admin drops a pending valuation outright
+    client.propose_valuation(&owner, &property_id, &6_000, &None);
+    assert!(client.reject_valuation(&admin, &property_id));
+    assert_eq!(client.get_pending_valuation(&property_id), None);
+}
+
+// =========================================================================
+// Share Token Interface
+// =========================================================================
+
+#[test]
+fn test_share_token_transfer() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    client.transfer(&property_id, &owner, &investor, &250);
+    assert_eq!(env.events().all().len(), 1);
+    assert_eq!(client.balance(&property_id, &owner), 750);
+    assert_eq!(client.balance(&property_id, &investor), 250);
+
+    assert_eq!(client.decimals(&property_id), SHARE_DECIMALS);
+    assert_eq!(
+        client.name(&property_id),
+        String::from_str(&env, "Beach House")
+    );
+    assert_eq!(client.symbol(&property_id), String::from_str(&env, "APS"));
+}
+
+#[test]
+fn test_share_token_approve_and_transfer_from() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let expiration = env.ledger().sequence() + 100;
+    client.approve(&property_id, &owner, &spender, &300, &expiration);
+    assert_eq!(client.allowance(&property_id, &owner, &spender), 300);
+
+    client.transfer_from(&property_id, &spender, &owner, &investor, &200);
+    assert_eq!(client.allowance(&property_id, &owner, &spender), 100);
+    assert_eq!(client.balance(&property_id, &investor), 200);
+}
+
+#[test]
+fn test_share_token_transfer_from_exceeds_allowance() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let expiration = env.ledger().sequence() + 100;
+    client.approve(&property_id, &owner, &spender, &100, &expiration);
+    assert_eq!(
+        client.try_transfer_from(&property_id, &spender, &owner, &spender, &101),
+        Err(Ok(ContractError::InsufficientAllowance.into()))
+    );
+}
+
+#[test]
+fn test_share_token_transfer_inactive_property() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    client.deactivate_property(&admin, &property_id);
+    assert_eq!(
+        client.try_transfer(&property_id, &owner, &investor, &10),
+        Err(Ok(ContractError::PropertyNotActive.into()))
+    );
+}
+
+// =========================================================================
+// Primary Offering
+// =========================================================================
+
+fn create_payment_token(env: &Env) -> Address {
+    let issuer = Address::generate(env);
+    env.register_stellar_asset_contract_v2(issuer).address()
+}
+
+fn offering_terms(payment_token: &Address, soft_cap: u64, end_time: u64) -> OfferingTerms {
+    OfferingTerms {
+        payment_token: payment_token.clone(),
+        price_per_share: 100,
+        shares_offered: 500,
+        min_purchase: 10,
+        max_purchase: 400,
+        soft_cap,
+        end_time,
+    }
+}
+
+#[test]
+fn test_offering_successful_sale() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.verify_property(&admin, &property_id);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+
+    let offering_id =
+        client.open_offering(&owner, &property_id, &offering_terms(&usdc, 100, 1_000));
+    assert_eq!(client.balance(&property_id, &owner), 500);
+
+    client.buy_shares(&buyer, &offering_id, &300);
+    let token = TokenClient::new(&env, &usdc);
+    assert_eq!(token.balance(&buyer), 70_000);
+    assert_eq!(token.balance(&client.address), 30_000);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        client.close_offering(&offering_id),
+        OfferingStatus::Successful
+    );
+    assert_eq!(token.balance(&owner), 30_000);
+    assert_eq!(client.balance(&property_id, &owner), 700);
+
+    client.claim_offering(&buyer, &offering_id);
+    assert_eq!(client.balance(&property_id, &buyer), 300);
+    assert!(client.get_purchase(&offering_id, &buyer).unwrap().claimed);
+}
+
+#[test]
+fn test_offering_soft_cap_missed_refunds_buyers() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.verify_property(&admin, &property_id);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+
+    let offering_id =
+        client.open_offering(&owner, &property_id, &offering_terms(&usdc, 200, 1_000));
+    client.buy_shares(&buyer, &offering_id, &50);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.close_offering(&offering_id), OfferingStatus::Failed);
+    assert_eq!(client.balance(&property_id, &owner), 1_000);
+
+    client.claim_offering(&buyer, &offering_id);
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&buyer), 100_000);
+    assert_eq!(client.balance(&property_id, &buyer), 0);
+}
+
+#[test]
+fn test_offering_purchase_above_maximum() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.verify_property(&admin, &property_id);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+
+    let offering_id =
+        client.open_offering(&owner, &property_id, &offering_terms(&usdc, 100, 1_000));
+    client.buy_shares(&buyer, &offering_id, &300);
+    assert_eq!(
+        client.try_buy_shares(&buyer, &offering_id, &101),
+        Err(Ok(ContractError::AmountOutOfRange.into()))
+    );
+}
+
+#[test]
+fn test_offering_requires_verified_property() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let usdc = create_payment_token(&env);
+    assert_eq!(
+        client.try_open_offering(&owner, &property_id, &offering_terms(&usdc, 100, 1_000)),
+        Err(Ok(ContractError::PropertyNotVerified.into()))
+    );
+}
+
+// =========================================================================
+// Dividends
+// =========================================================================
+
+#[test]
+fn test_dividends_pro_rata_and_idempotent_claim() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &investor, &250);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &10_000);
+    client.distribute_dividends(&owner, &property_id, &usdc, &10_000);
+
+    assert_eq!(
+        client.get_claimable_dividends(&property_id, &investor),
+        2_500
+    );
+    assert_eq!(client.get_claimable_dividends(&property_id, &owner), 7_500);
+
+    assert_eq!(client.claim_dividends(&investor, &property_id), 2_500);
+    assert_eq!(client.claim_dividends(&investor, &property_id), 0);
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&investor), 2_500);
+}
+
+#[test]
+fn test_dividends_follow_share_transfers() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &2_000);
+    client.distribute_dividends(&owner, &property_id, &usdc, &1_000);
+
+    // Shares bought after the first distribution do not earn it
+    client.transfer(&property_id, &owner, &investor, &500);
+    client.distribute_dividends(&owner, &property_id, &usdc, &1_000);
+
+    assert_eq!(client.get_claimable_dividends(&property_id, &investor), 500);
+    assert_eq!(client.get_claimable_dividends(&property_id, &owner), 1_500);
+
+    assert_eq!(client.claim_dividends(&owner, &property_id), 1_500);
+    assert_eq!(client.claim_dividends(&investor, &property_id), 500);
+    assert_eq!(TokenClient::new(&env, &usdc).balance(&client.address), 0);
+}
+
+// =========================================================================
+// Compliance
+// =========================================================================
+
+fn compliance_rules(env: &Env, max_holding: u64, lockup_period: u64) -> ComplianceRules {
+    ComplianceRules {
+        allowed_jurisdictions: vec![env, String::from_str(env, "PT")],
+        max_holding,
+        lockup_period,
+    }
+}
+
+#[test]
+fn test_compliance_allowlist_jurisdiction_and_holding_cap() {
+    let (env, client, admin) = setup_registry();
+    let officer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    grant_role(&client, &admin, &officer, Role::Compliance);
+    client.set_compliance_rules(&officer, &property_id, &compliance_rules(&env, 100, 0));
+
+    assert_eq!(
+        client.try_transfer(&property_id, &owner, &investor, &50),
+        Err(Ok(ContractError::NotAllowlisted.into()))
+    );
+
+    let us = String::from_str(&env, "US");
+    client.allowlist_investor(&officer, &property_id, &investor, &us);
+    assert_eq!(
+        client.try_transfer(&property_id, &owner, &investor, &50),
+        Err(Ok(ContractError::JurisdictionNotAllowed.into()))
+    );
+
+    let pt = String::from_str(&env, "PT");
+    client.allowlist_investor(&officer, &property_id, &investor, &pt);
+    client.transfer(&property_id, &owner, &investor, &50);
+    assert_eq!(client.balance(&property_id, &investor), 50);
+    assert_eq!(
+        client.try_transfer(&property_id, &owner, &investor, &51),
+        Err(Ok(ContractError::HoldingCapExceeded.into()))
+    );
+
+    client.remove_investor(&officer, &property_id, &investor);
+    assert!(client.get_investor(&property_id, &investor).is_none());
+}
+
+#[test]
+fn test_compliance_frozen_address() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &investor, &100);
+
+    client.set_frozen(&admin, &property_id, &investor, &true);
+    assert!(client.is_frozen(&property_id, &investor));
+    assert_eq!(
+        client.try_transfer(&property_id, &investor, &owner, &10),
+        Err(Ok(ContractError::AddressFrozen.into()))
+    );
+    assert_eq!(
+        client.try_transfer(&property_id, &owner, &investor, &10),
+        Err(Ok(ContractError::AddressFrozen.into()))
+    );
+
+    client.set_frozen(&admin, &property_id, &investor, &false);
+    client.transfer(&property_id, &investor, &owner, &10);
+    assert_eq!(client.balance(&property_id, &investor), 90);
+}
+
+#[test]
+fn test_compliance_lockup_after_offering_purchase() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.verify_property(&admin, &property_id);
+    client.set_compliance_rules(&admin, &property_id, &compliance_rules(&env, 0, 5_000));
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+    let offering_id =
+        client.open_offering(&owner, &property_id, &offering_terms(&usdc, 100, 1_000));
+
+    // Non-allowlisted buyers cannot take part in the sale
+    assert_eq!(
+        client.try_buy_shares(&buyer, &offering_id, &200),
+        Err(Ok(ContractError::NotAllowlisted.into()))
+    );
+
+    let pt = String::from_str(&env, "PT");
+    client.allowlist_investor(&admin, &property_id, &buyer, &pt);
+    client.allowlist_investor(&admin, &property_id, &stranger, &pt);
+    client.buy_shares(&buyer, &offering_id, &200);
+    env.ledger().set_timestamp(1_000);
+    client.close_offering(&offering_id);
+    client.claim_offering(&buyer, &offering_id);
+    assert_eq!(client.get_locked_until(&property_id, &buyer), 6_000);
+
+    assert_eq!(
+        client.try_transfer(&property_id, &buyer, &stranger, &50),
+        Err(Ok(ContractError::SharesLocked.into()))
+    );
+    env.ledger().set_timestamp(6_000);
+    client.transfer(&property_id, &buyer, &stranger, &50);
+    assert_eq!(client.balance(&property_id, &stranger), 50);
+}
+
+#[test]
+fn test_compliance_blocks_collateral_seizure() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &375_000);
+    client.set_price(&admin, &PriceFeed::Property(property_id), &800_000);
+    client.set_compliance_rules(&admin, &property_id, &compliance_rules(&env, 0, 0));
+
+    let liquidator = Address::generate(&env);
+    grant_role(&client, &admin, &liquidator, Role::Liquidator);
+    StellarAssetClient::new(&env, &asset).mint(&liquidator, &1_000_000);
+    assert_eq!(
+        client.try_liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000),
+        Err(Ok(ContractError::NotAllowlisted.into()))
+    );
+
+    let pt = String::from_str(&env, "PT");
+    client.allowlist_investor(&admin, &property_id, &liquidator, &pt);
+    assert_eq!(
+        client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000),
+        246
+    );
+}
+
+// =========================================================================
+// Secondary Market
+// =========================================================================
+
+/// Configures the market with a 1% maker and 2% taker fee
+fn setup_market(env: &Env, client: &PropertyTokenContractClient, admin: &Address) -> MarketConfig {
+    let config = MarketConfig {
+        quote_token: create_payment_token(env),
+        treasury: Address::generate(env),
+        maker_fee_bps: 100,
+        taker_fee_bps: 200,
+    };
+    run_operation(
+        client,
+        admin,
+        TimelockOperation::SetMarketConfig(config.clone()),
+    );
+    config
+}
+
+#[test]
+fn test_market_partial_fills_in_price_time_priority() {
+    let (env, client, admin) = setup_registry();
+    let config = setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &seller, &100);
+    StellarAssetClient::new(&env, &config.quote_token).mint(&buyer, &10_000);
+
+    let first_ask = client.place_order(&seller, &property_id, &OrderSide::Sell, &10, &100);
+    let second_ask = client.place_order(&owner, &property_id, &OrderSide::Sell, &10, &100);
+    let best_ask = client.place_order(&owner, &property_id, &OrderSide::Sell, &9, &50);
+    let book = client.get_order_book(&property_id, &OrderSide::Sell);
+    assert_eq!(book.get(0).unwrap().id, best_ask);
+    assert_eq!(book.get(1).unwrap().id, first_ask);
+    assert_eq!(book.get(2).unwrap().id, second_ask);
+
+    // 180 shares at up to 11, escrowing the notional plus the 2% maximum fee
+    let bid = client.place_order(&buyer, &property_id, &OrderSide::Buy, &11, &180);
+    assert_eq!(client.get_order(&bid).escrow, 1_980 + 39);
+
+    assert_eq!(client.match_orders(&property_id, &10), 3);
+    assert_eq!(client.balance(&property_id, &buyer), 180);
+
+    // The bid is the taker and trades at each maker's price: 50@9, 100@10, 30@10
+    let token = TokenClient::new(&env, &config.quote_token);
+    assert_eq!(token.balance(&buyer), 10_000 - 1_750 - 35);
+    assert_eq!(token.balance(&seller), 1_000 - 10);
+    assert_eq!(token.balance(&owner), 450 - 4 + 300 - 3);
+    assert_eq!(token.balance(&config.treasury), 35 + 4 + 10 + 3);
+    assert_eq!(token.balance(&client.address), 0);
+
+    let filled_bid = client.get_order(&bid);
+    assert_eq!(filled_bid.status, OrderStatus::Filled);
+    assert_eq!(filled_bid.escrow, 0);
+    let partial_ask = client.get_order(&second_ask);
+    assert_eq!(partial_ask.status, OrderStatus::Open);
+    assert_eq!(partial_ask.filled, 30);
+    assert_eq!(
+        client.get_order_book(&property_id, &OrderSide::Sell).len(),
+        1
+    );
+    assert_eq!(
+        client.get_order_book(&property_id, &OrderSide::Buy).len(),
+        0
+    );
+}
+
+#[test]
+fn test_market_cancel_returns_escrow() {
+    let (env, client, admin) = setup_registry();
+    let config = setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    StellarAssetClient::new(&env, &config.quote_token).mint(&buyer, &10_000);
+
+    let ask = client.place_order(&owner, &property_id, &OrderSide::Sell, &20, &100);
+    let bid = client.place_order(&buyer, &property_id, &OrderSide::Buy, &10, &100);
+    assert_eq!(client.balance(&property_id, &owner), 900);
+    assert_eq!(client.match_orders(&property_id, &10), 0);
+
+    client.cancel_order(&owner, &ask);
+    client.cancel_order(&buyer, &bid);
+    assert_eq!(client.balance(&property_id, &owner), 1_000);
+    assert_eq!(
+        TokenClient::new(&env, &config.quote_token).balance(&buyer),
+        10_000
+    );
+    assert_eq!(client.get_order(&ask).status, OrderStatus::Cancelled);
+    assert!(client
+        .get_order_book(&property_id, &OrderSide::Sell)
+        .is_empty());
+}
+
+#[test]
+fn test_market_config_independent_of_oracle_config() {
+    let (env, client, admin) = setup_registry();
+    let oracle_config = OracleConfig {
+        max_age: 3_600,
+        max_deviation_bps: 1_000,
+    };
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetOracleConfig(oracle_config.clone()),
+    );
+    let market_config = setup_market(&env, &client, &admin);
+
+    assert_eq!(client.get_market_config(), Some(market_config));
+    assert_eq!(client.get_oracle_config(), oracle_config);
+}
+
+#[test]
+fn test_market_full_book_evicts_worst_order() {
+    let (env, client, admin) = setup_registry();
+    setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let worst = client.place_order(&owner, &property_id, &OrderSide::Sell, &30, &1);
+    for _ in 1..MAX_BOOK_ORDERS {
+        client.place_order(&owner, &property_id, &OrderSide::Sell, &20, &1);
+    }
+    assert_eq!(
+        client.try_place_order(&owner, &property_id, &OrderSide::Sell, &30, &1),
+        Err(Ok(ContractError::CapExceeded.into()))
+    );
+
+    let best = client.place_order(&owner, &property_id, &OrderSide::Sell, &10, &1);
+    let book = client.get_order_book(&property_id, &OrderSide::Sell);
+    assert_eq!(book.len(), MAX_BOOK_ORDERS);
+    assert_eq!(book.first_unchecked().id, best);
+    assert_eq!(client.get_order(&worst).status, OrderStatus::Cancelled);
+    assert_eq!(
+        client.balance(&property_id, &owner),
+        1_000 - MAX_BOOK_ORDERS as i128
+    );
+}
+
+#[test]
+fn test_market_match_skips_frozen_seller() {
+    let (env, client, admin) = setup_registry();
+    let config = setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &seller, &100);
+    StellarAssetClient::new(&env, &config.quote_token).mint(&buyer, &10_000);
+
+    let ask = client.place_order(&seller, &property_id, &OrderSide::Sell, &10, &100);
+    client.place_order(&buyer, &property_id, &OrderSide::Buy, &10, &100);
+    client.set_frozen(&admin, &property_id, &seller, &true);
+
+    assert_eq!(client.match_orders(&property_id, &10), 0);
+    assert!(client
+        .get_order_book(&property_id, &OrderSide::Sell)
+        .is_empty());
+    assert_eq!(client.get_order(&ask).status, OrderStatus::Suspended);
+    assert_eq!(
+        TokenClient::new(&env, &config.quote_token).balance(&seller),
+        0
+    );
+    assert_eq!(
+        client.try_cancel_order(&seller, &ask),
+        Err(Ok(ContractError::AddressFrozen.into()))
+    );
+
+    client.set_frozen(&admin, &property_id, &seller, &false);
+    client.cancel_order(&seller, &ask);
+    assert_eq!(client.balance(&property_id, &seller), 100);
+    assert_eq!(client.get_order(&ask).status, OrderStatus::Cancelled);
+}
+
+#[test]
+fn test_market_full_book_suspends_frozen_sellers_worst_order() {
+    let (env, client, admin) = setup_registry();
+    setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &seller, &5);
+
+    let worst = client.place_order(&seller, &property_id, &OrderSide::Sell, &30, &5);
+    for _ in 1..MAX_BOOK_ORDERS {
+        client.place_order(&owner, &property_id, &OrderSide::Sell, &20, &1);
+    }
+    client.set_frozen(&admin, &property_id, &seller, &true);
+
+    // Evicting the frozen seller's ask must not block a healthy user
+    let best = client.place_order(&owner, &property_id, &OrderSide::Sell, &10, &1);
+    let book = client.get_order_book(&property_id, &OrderSide::Sell);
+    assert_eq!(book.len(), MAX_BOOK_ORDERS);
+    assert_eq!(book.first_unchecked().id, best);
+    assert_eq!(client.get_order(&worst).status, OrderStatus::Suspended);
+    assert_eq!(client.balance(&property_id, &seller), 0);
+
+    client.set_frozen(&admin, &property_id, &seller, &false);
+    client.cancel_order(&seller, &worst);
+    assert_eq!(client.balance(&property_id, &seller), 5);
+}
+
+#[test]
+fn test_market_cancel_requires_owner() {
+    let (env, client, admin) = setup_registry();
+    setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let ask = client.place_order(&owner, &property_id, &OrderSide::Sell, &20, &100);
+    assert_eq!(
+        client.try_cancel_order(&Address::generate(&env), &ask),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+}
+
+#[test]
+fn test_market_match_cancels_non_compliant_bid() {
+    let (env, client, admin) = setup_registry();
+    let config = setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.set_compliance_rules(&admin, &property_id, &compliance_rules(&env, 0, 0));
+    let pt = String::from_str(&env, "PT");
+    client.allowlist_investor(&admin, &property_id, &buyer, &pt);
+    StellarAssetClient::new(&env, &config.quote_token).mint(&buyer, &10_000);
+
+    client.place_order(&owner, &property_id, &OrderSide::Sell, &10, &100);
+    let bid = client.place_order(&buyer, &property_id, &OrderSide::Buy, &10, &100);
+    client.set_frozen(&admin, &property_id, &buyer, &true);
+
+    assert_eq!(client.match_orders(&property_id, &10), 0);
+    assert_eq!(client.get_order(&bid).status, OrderStatus::Cancelled);
+    assert_eq!(
+        TokenClient::new(&env, &config.quote_token).balance(&buyer),
+        10_000
+    );
+    assert_eq!(
+        client.get_order_book(&property_id, &OrderSide::Sell).len(),
+        1
+    );
+}
+
+// =========================================================================
+// Governance
+// =========================================================================
+
+#[test]
+fn test_balance_checkpoints_by_ledger() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    for ledger in 11..=17 {
+        env.ledger().set_sequence_number(ledger);
+        client.transfer(&property_id, &owner, &alice, &10);
+    }
+    // Changes within one ledger collapse into one checkpoint
+    client.transfer(&property_id, &owner, &alice, &5);
+
+    assert_eq!(client.get_balance_at(&property_id, &alice, &10), 0);
+    for ledger in 11..17 {
+        assert_eq!(
+            client.get_balance_at(&property_id, &alice, &ledger),
+            (ledger as u64 - 10) * 10
+        );
+    }
+    assert_eq!(client.get_balance_at(&property_id, &alice, &17), 75);
+    assert_eq!(client.get_balance_at(&property_id, &alice, &100), 75);
+    assert_eq!(client.get_balance_at(&property_id, &owner, &10), 1_000);
+    assert_eq!(client.get_balance_at(&property_id, &owner, &17), 925);
+
+    // Contract escrow never votes and is not checkpointed
+    client.transfer(&property_id, &owner, &client.address, &100);
+    assert_eq!(client.balance(&property_id, &client.address), 100);
+    assert_eq!(client.get_balance_at(&property_id, &client.address, &17), 0);
+}
+
+#[test]
+fn test_governance_snapshot_prevents_double_voting() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let manager = Address::generate(&env);
+    env.ledger().set_sequence_number(10);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &alice, &300);
+    client.transfer(&property_id, &owner, &bob, &200);
+
+    let proposal_id = client.create_proposal(
+        &alice,
+        &property_id,
+        &ProposalAction::ChangeManager(manager.clone()),
+    );
+    assert_eq!(
+        client.try_vote(&alice, &proposal_id, &true),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    // Shares moved after the snapshot keep voting with their previous holder
+    env.ledger().set_sequence_number(11);
+    client.transfer(&property_id, &alice, &carol, &300);
+    assert_eq!(client.vote(&alice, &proposal_id, &true), 300);
+    assert_eq!(
+        client.try_vote(&carol, &proposal_id, &true),
+        Err(Ok(ContractError::InsufficientShares.into()))
+    );
+    assert_eq!(
+        client.try_vote(&alice, &proposal_id, &true),
+        Err(Ok(ContractError::AlreadyVoted.into()))
+    );
+    assert_eq!(client.vote(&bob, &proposal_id, &false), 200);
+    assert_eq!(client.get_balance_at(&property_id, &alice, &10), 300);
+    assert_eq!(client.get_balance_at(&property_id, &alice, &11), 0);
+
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+    env.ledger().set_timestamp(604_800);
+    assert_eq!(
+        client.execute_proposal(&proposal_id),
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.get_property(&property_id).owner, manager);
+}
+
+#[test]
+fn test_governance_executes_once_majority_of_supply_approves() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let policy = DividendPolicy {
+        payout_ratio_bps: 8_000,
+        distribution_interval: 2_592_000,
+    };
+
+    let proposal_id = client.create_proposal(
+        &owner,
+        &property_id,
+        &ProposalAction::SetDividendPolicy(policy.clone()),
+    );
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    client.vote(&owner, &proposal_id, &true);
+
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.get_dividend_policy(&property_id), Some(policy));
+}
+
+#[test]
+fn test_governance_config_independent_of_oracle_config() {
+    let (_env, client, admin) = setup_registry();
+    let oracle_config = OracleConfig {
+        max_age: 3_600,
+        max_deviation_bps: 1_000,
+    };
+    let governance_config = GovernanceConfig {
+        voting_period: 86_400,
+        quorum_bps: 3_000,
+        approval_threshold_bps: 6_000,
+    };
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetOracleConfig(oracle_config.clone()),
+    );
+    client.set_governance_config(&admin, &governance_config);
+
+    assert_eq!(client.get_governance_config(), governance_config);
+    assert_eq!(client.get_oracle_config(), oracle_config);
+}
+
+#[test]
+fn test_governance_defeated_without_quorum() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &holder, &100);
+
+    let proposal_id = client.create_proposal(
+        &holder,
+        &property_id,
+        &ProposalAction::UpdateValuation(20_000_000_000_000),
+    );
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    client.vote(&holder, &proposal_id, &true);
+
+    env.ledger().set_timestamp(604_800);
+    assert_eq!(
+        client.execute_proposal(&proposal_id),
+        ProposalStatus::Defeated
+    );
+    assert_eq!(
+        client.get_property(&property_id).valuation,
+        10_000_000_000_000
+    );
+}
+
+// =========================================================================
+// Buyout
+// =========================================================================
+
+fn buyout_terms(payment_token: &Address, total_payment: i128) -> BuyoutTerms {
+    BuyoutTerms {
+        payment_token: payment_token.clone(),
+        total_payment,
+        deadline: 1_000,
+    }
+}
+
+/// Creates a `SellProperty` proposal and votes for it with the proposer's shares
+fn approve_sale(
+    env: &Env,
+    client: &PropertyTokenContractClient,
+    holder: &Address,
+    property_id: u64,
+    buyer: &Address,
+    price: i128,
+) -> u64 {
+    let proposal_id = client.create_proposal(
+        holder,
+        &property_id,
+        &ProposalAction::SellProperty(buyer.clone(), price),
+    );
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    client.vote(holder, &proposal_id, &true);
+    proposal_id
+}
+
+#[test]
+fn test_buyout_redemption_and_delisting() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &alice, &300);
+    client.transfer(&property_id, &owner, &bob, &100);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &100_000);
+    let proposal_id = approve_sale(&env, &client, &owner, property_id, &owner, 100_000);
+    client.start_buyout(
+        &owner,
+        &property_id,
+        &buyout_terms(&usdc, 100_000),
+        &proposal_id,
+    );
+    assert_eq!(
+        client.try_transfer(&property_id, &alice, &bob, &10),
+        Err(Ok(ContractError::TradingFrozen.into()))
+    );
+
+    assert_eq!(client.redeem_shares(&alice, &property_id), 30_000);
+    assert_eq!(client.balance(&property_id, &alice), 0);
+    assert_eq!(
+        client.try_finalize_buyout(&property_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.finalize_buyout(&property_id), 70_000);
+    assert!(!client.get_property(&property_id).is_active);
+    assert_eq!(
+        client.get_buyout(&property_id).unwrap().status,
+        BuyoutStatus::Completed
+    );
+
+    // Late holders still recover their payout from escrow
+    assert_eq!(client.redeem_shares(&bob, &property_id), 10_000);
+    let token = TokenClient::new(&env, &usdc);
+    assert_eq!(token.balance(&alice), 30_000);
+    assert_eq!(token.balance(&bob), 10_000);
+    assert_eq!(token.balance(&client.address), 60_000);
+}
+
+#[test]
+fn test_buyout_unclaimed_payouts_swept_to_bidder() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &holder, &200);
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+
+    let proposal_id = approve_sale(&env, &client, &owner, property_id, &buyer, 100_000);
+    client.start_buyout(
+        &buyer,
+        &property_id,
+        &buyout_terms(&usdc, 100_000),
+        &proposal_id,
+    );
+    assert_eq!(client.redeem_shares(&owner, &property_id), 80_000);
+
+    // The other holder never redeems
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.finalize_buyout(&property_id), 20_000);
+    assert_eq!(
+        client.try_sweep_buyout(&property_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    env.ledger().set_timestamp(1_000 + BUYOUT_CLAIM_PERIOD);
+    assert_eq!(
+        client.try_redeem_shares(&holder, &property_id),
+        Err(Ok(ContractError::Closed.into()))
+    );
+    assert_eq!(client.sweep_buyout(&property_id), 20_000);
+    let buyout = client.get_buyout(&property_id).unwrap();
+    assert_eq!(buyout.status, BuyoutStatus::Swept);
+    assert_eq!(buyout.escrow(), 0);
+
+    let token = TokenClient::new(&env, &usdc);
+    assert_eq!(token.balance(&buyer), 20_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.try_sweep_buyout(&property_id),
+        Err(Ok(ContractError::Closed.into()))
+    );
+}
+
+#[test]
+fn test_buyout_by_buyer_approved_in_governance() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &50_000);
+
+    let proposal_id = approve_sale(&env, &client, &owner, property_id, &buyer, 50_000);
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Approved
+    );
+    assert_eq!(
+        client.try_start_buyout(
+            &buyer,
+            &property_id,
+            &buyout_terms(&usdc, 49_999),
+            &proposal_id
+        ),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+
+    client.start_buyout(
+        &buyer,
+        &property_id,
+        &buyout_terms(&usdc, 50_000),
+        &proposal_id,
+    );
+    assert_eq!(
+        client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+    assert_eq!(client.redeem_shares(&owner, &property_id), 50_000);
+}
+
+#[test]
+fn test_owner_buyout_needs_shareholder_approval() {
+    let (env, client, _admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &alice, &300);
+    client.transfer(&property_id, &owner, &bob, &300);
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &100_000);
+
+    // The owner's own proposal fails once the other holders vote it down
+    let rejected = approve_sale(&env, &client, &owner, property_id, &owner, 1);
+    client.vote(&alice, &rejected, &false);
+    client.vote(&bob, &rejected, &false);
+    env.ledger().set_timestamp(604_800);
+    assert_eq!(client.execute_proposal(&rejected), ProposalStatus::Defeated);
+    let terms = BuyoutTerms {
+        payment_token: usdc.clone(),
+        total_payment: 1,
+        deadline: 1_000_000,
+    };
+    assert_eq!(
+        client.try_start_buyout(&owner, &property_id, &terms, &rejected),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+
+    let approved = approve_sale(&env, &client, &alice, property_id, &owner, 100_000);
+    client.vote(&bob, &approved, &true);
+    assert_eq!(
+        client.get_proposal(&approved).status,
+        ProposalStatus::Approved
+    );
+    client.start_buyout(
+        &owner,
+        &property_id,
+        &BuyoutTerms {
+            total_payment: 100_000,
+            ..terms
+        },
+        &approved,
+    );
+    assert_eq!(client.redeem_shares(&alice, &property_id), 30_000);
+}
+
+#[test]
+fn test_buyout_lets_escrowed_shares_return_for_redemption() {
+    let (env, client, admin) = setup_registry();
+    setup_market(&env, &client, &admin);
+    let owner = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    client.transfer(&property_id, &owner, &seller, &100);
+    let ask = client.place_order(&seller, &property_id, &OrderSide::Sell, &10, &100);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &100_000);
+    let proposal_id = approve_sale(&env, &client, &owner, property_id, &owner, 100_000);
+    client.start_buyout(
+        &owner,
+        &property_id,
+        &buyout_terms(&usdc, 100_000),
+        &proposal_id,
+    );
+    assert_eq!(
+        client.try_place_order(&seller, &property_id, &OrderSide::Buy, &10, &1),
+        Err(Ok(ContractError::TradingFrozen.into()))
+    );
+
+    client.cancel_order(&seller, &ask);
+    assert_eq!(client.redeem_shares(&seller, &property_id), 10_000);
+}
+
+#[test]
+fn test_buyout_finalized_with_shares_in_escrow() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let owner = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &owner);
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &100_000);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &100_000);
+
+    let offering_id =
+        client.open_offering(&owner, &property_id, &offering_terms(&usdc, 100, 1_000));
+    client.buy_shares(&buyer, &offering_id, &300);
+    let proposal_id = approve_sale(&env, &client, &owner, property_id, &owner, 100_000);
+    let terms = BuyoutTerms {
+        payment_token: usdc.clone(),
+        total_payment: 100_000,
+        deadline: 10_000,
+    };
+    assert_eq!(
+        client.try_start_buyout(&owner, &property_id, &terms, &proposal_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    // The buyer leaves the purchased shares unclaimed in escrow
+    env.ledger().set_timestamp(1_000);
+    client.close_offering(&offering_id);
+    client.transfer(&property_id, &owner, &borrower, &100);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &100,
+    );
+    client.start_buyout(&owner, &property_id, &terms, &proposal_id);
+
+    env.ledger().set_timestamp(10_000);
+    assert_eq!(client.finalize_buyout(&property_id), 100_000);
+    assert!(!client.get_property(&property_id).is_active);
+
+    client.claim_offering(&buyer, &offering_id);
+    assert_eq!(client.redeem_shares(&buyer, &property_id), 30_000);
+    client.remove_collateral(&borrower, &pool_id, &100);
+    assert_eq!(client.redeem_shares(&borrower, &property_id), 10_000);
+    assert_eq!(client.redeem_shares(&owner, &property_id), 60_000);
+    assert_eq!(client.get_buyout(&property_id).unwrap().escrow(), 0);
+}
+
+// =========================================================================
+// Timelock
+// =========================================================================
+
+#[test]
+fn test_timelock_delays_risk_param_change() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    set_timelock_delays(&client, &admin, 86_400);
+    let params = PoolRiskParams {
+        collateral_factor: 600_000_000_000_000_000,
+        liquidation_threshold: 700_000_000_000_000_000,
+        liquidation_penalty: 50_000_000_000_000_000,
+        reserve_factor: 2000,
+    };
+
+    let operation_id = client.queue_operation(
+        &admin,
+        &TimelockOperation::SetPoolRiskParams(pool_id.clone(), params),
+    );
+    assert_eq!(
+        client.try_execute_operation(&admin, &operation_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+    assert_eq!(
+        client.get_pool(&pool_id).collateral_factor,
+        750_000_000_000_000_000
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400);
+    client.execute_operation(&admin, &operation_id);
+    let pool = client.get_pool(&pool_id);
+    assert_eq!(pool.collateral_factor, 600_000_000_000_000_000);
+    assert_eq!(pool.liquidation_threshold, 700_000_000_000_000_000);
+    assert_eq!(pool.reserve_factor, 2000);
+}
+
+#[test]
+fn test_timelock_role_grant_waits_for_delay() {
+    let (env, client, admin) = setup_registry();
+    set_timelock_delays(&client, &admin, 86_400);
+    let oracle = Address::generate(&env);
+
+    let operation_id = client.queue_operation(
+        &admin,
+        &TimelockOperation::GrantRole(oracle.clone(), Role::Oracle),
+    );
+    assert!(!client.has_role(&oracle, &Role::Oracle));
+    assert_eq!(
+        client.try_execute_operation(&admin, &operation_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400);
+    client.execute_operation(&admin, &operation_id);
+    assert!(client.has_role(&oracle, &Role::Oracle));
+
+    // The admin role itself only moves through admin transfer
+    assert_eq!(
+        client.try_queue_operation(&admin, &TimelockOperation::GrantRole(oracle, Role::Admin)),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+}
+
+#[test]
+fn test_timelock_admin_transfer() {
+    let (env, client, admin) = setup_registry();
+    let new_admin = Address::generate(&env);
+
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::TransferAdmin(new_admin.clone()),
+    );
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+    assert_eq!(
+        client.try_queue_operation(&admin, &TimelockOperation::TransferAdmin(admin.clone())),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_timelock_lowering_delays_waits_for_longest_delay() {
+    let (env, client, admin) = setup_registry();
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetConfig(TimelockConfig {
+            admin_transfer_delay: 604_800,
+            ..TimelockConfig::uniform(86_400)
+        }),
+    );
+
+    let operation_id = client.queue_operation(
+        &admin,
+        &TimelockOperation::SetConfig(TimelockConfig::uniform(MIN_DELAY)),
+    );
+    assert_eq!(
+        client.get_queued_operation(&operation_id).unwrap().eta,
+        env.ledger().timestamp() + 604_800
+    );
+}
+
+#[test]
+fn test_timelock_requires_minimum_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = PropertyTokenContractClient::new(&env, &env.register(PropertyTokenContract, ()));
+    let admin = Address::generate(&env);
+    let name = String::from_str(&env, "Akkuea Property Share");
+    let symbol = String::from_str(&env, "APS");
+    assert_eq!(
+        client.try_initialize(&admin, &name, &symbol, &0),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+
+    client.initialize(&admin, &name, &symbol, &3_600);
+    assert_eq!(client.get_timelock_config(), TimelockConfig::uniform(3_600));
+    assert_eq!(
+        client.try_queue_operation(
+            &admin,
+            &TimelockOperation::SetConfig(TimelockConfig {
+                config_delay: 3_599,
+                ..TimelockConfig::uniform(86_400)
+            }),
+        ),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+}
+
+#[test]
+fn test_timelock_delays_config_changes() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    set_timelock_delays(&client, &admin, 86_400);
+    let mut config = client.get_lending_config();
+    config.flash_loan_fee_bps = 50;
+    let caps = PoolCaps {
+        supply_cap: 2_000_000,
+        borrow_cap: 0,
+        user_borrow_cap: 0,
+    };
+
+    let config_op =
+        client.queue_operation(&admin, &TimelockOperation::SetLendingConfig(config.clone()));
+    let caps_op = client.queue_operation(
+        &admin,
+        &TimelockOperation::SetPoolCaps(pool_id.clone(), caps.clone()),
+    );
+    assert_eq!(
+        client.try_execute_operation(&admin, &config_op),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+    assert_eq!(
+        client.try_execute_operation(&admin, &caps_op),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400);
+    client.execute_operation(&admin, &config_op);
+    client.execute_operation(&admin, &caps_op);
+    assert_eq!(client.get_lending_config(), config);
+    assert_eq!(client.get_pool_caps(&pool_id), caps);
+}
+
+#[test]
+fn test_guardian_can_only_cancel_and_pause() {
+    let (env, client, admin) = setup_registry();
+    let guardian = Address::generate(&env);
+    grant_role(&client, &admin, &guardian, Role::Guardian);
+    set_timelock_delays(&client, &admin, 86_400);
+
+    let verifier = Address::generate(&env);
+    let operation = TimelockOperation::GrantRole(verifier.clone(), Role::Verifier);
+    assert_eq!(
+        client.try_queue_operation(&guardian, &operation),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+    let operation_id = client.queue_operation(&admin, &operation);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400);
+    assert_eq!(
+        client.try_execute_operation(&guardian, &operation_id),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+    client.cancel_operation(&guardian, &operation_id);
+    assert_eq!(client.get_queued_operation(&operation_id), None);
+    assert_eq!(
+        client.try_execute_operation(&admin, &operation_id),
+        Err(Ok(ContractError::NotFound.into()))
+    );
+    assert!(!client.has_role(&verifier, &Role::Verifier));
+
+    client.pause(&guardian);
+    assert_eq!(
+        client.try_unpause(&guardian),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    client.unpause(&admin);
+}
+
+// =========================================================================
+// Upgrades
+// =========================================================================
+
+#[test]
+fn test_upgrade_requires_admin_and_pause() {
+    let (env, client, admin) = setup_registry();
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+
+    assert_eq!(
+        client.try_upgrade(&admin, &wasm_hash),
+        Err(Ok(ContractError::ContractNotPaused.into()))
+    );
+    client.pause(&admin);
+    assert_eq!(
+        client.try_upgrade(&Address::generate(&env), &wasm_hash),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_migrate(&Address::generate(&env), &10),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_migration_runs_in_batches_while_paused() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&lender, &1_000);
+    client.deposit(&lender, &pool_id, &1_000);
+    let owner = Address::generate(&env);
+    let first = register_test_property(&env, &client, &owner, 1_000);
+    register_test_property(&env, &client, &owner, 1_000);
+
+    // Pretend the records predate schema versioning
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&UpgradeKey::SchemaVersion);
+        env.storage()
+            .persistent()
+            .remove(&RegistryKey::ValuationHistory(first));
+        env.storage().instance().set(
+            &keys::StorageKey::PropertyPaused(first),
+            &PAUSE_SHARE_TRANSFER,
+        );
+    });
+    assert_eq!(client.get_schema_version(), 0);
+    assert_eq!(
+        client.try_migrate(&admin, &2),
+        Err(Ok(ContractError::ContractNotPaused.into()))
+    );
+
+    client.pause(&admin);
+    assert_eq!(
+        client.try_migrate(&admin, &(MAX_MIGRATION_BATCH + 1)),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+    let status = client.migrate(&admin, &2);
+    assert_eq!(
+        status,
+        MigrationStatus {
+            schema_version: 0,
+            next_property: 3,
+            next_pool: 0,
+        }
+    );
+    assert_eq!(client.get_migration_status(), status);
+    assert_eq!(
+        client.try_unpause(&admin),
+        Err(Ok(ContractError::MigrationPending.into()))
+    );
+
+    assert!(client.migrate(&admin, &2).is_complete());
+    assert_eq!(client.get_schema_version(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(
+        client.migrate_positions(&admin, &vec![&env, lender.clone()]),
+        1
+    );
+
+    client.unpause(&admin);
+    assert_eq!(client.get_property(&first).owner, owner);
+    assert_eq!(
+        client.get_property_paused_functions(&first),
+        PAUSE_SHARE_TRANSFER
+    );
+    assert_eq!(client.get_valuation_history(&first).len(), 1);
+    assert_eq!(client.get_deposit(&lender, &pool_id).unwrap().shares, 1_000);
+}
+
+#[test]
+fn test_migration_converts_original_layouts() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let owner = Address::generate(&env);
+    let borrower = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    // Rewrite the records in the layouts of the original deployment
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&UpgradeKey::SchemaVersion);
+        env.storage().instance().set(
+            &keys::StorageKey::Property(property_id),
+            &PropertyMetadataV1 {
+                property_id,
+                owner: owner.clone(),
+                name: String::from_str(&env, "Old House"),
+                description: String::from_str(&env, "Registered before verification"),
+                location: String::from_str(&env, "Lisbon"),
+                valuation: 1_000_000,
+                total_shares: 1_000,
+                created_at: 0,
+                is_active: true,
+            },
+        );
+        env.storage().persistent().set(
+            &LendingKey::BorrowPosition(borrower.clone(), pool_id.clone()),
+            &BorrowPositionV1 {
+                pool_id: pool_id.clone(),
+                borrower: borrower.clone(),
+                principal: 500,
+                index_at_borrow: PRECISION,
+                collateral_amount: 1_000,
+                collateral_asset: asset.clone(),
+                borrowed_at: 0,
+            },
+        );
+        env.storage().persistent().set(
+            &LendingKey::UserBorrows(borrower.clone()),
+            &vec![&env, pool_id.clone()],
+        );
+    });
+
+    client.pause(&admin);
+    assert!(client.migrate(&admin, &10).is_complete());
+    assert_eq!(
+        client.migrate_positions(&admin, &vec![&env, borrower.clone()]),
+        1
+    );
+    client.unpause(&admin);
+
+    let property = client.get_property(&property_id);
+    assert_eq!(property.name, String::from_str(&env, "Old House"));
+    assert!(!property.is_verified);
+    let position = client.get_borrow(&borrower, &pool_id).unwrap();
+    assert_eq!(position.principal, 500);
+    assert_eq!(position.collateral(), Collateral::Token(asset));
+}
+
+// =========================================================================
+// Lending Pool Entry Points
+// =========================================================================
+
+fn create_test_pool(env: &Env, client: &PropertyTokenContractClient, admin: &Address) -> String {
+    let pool_id = String::from_str(env, "USDC-POOL");
+    let asset_address = create_payment_token(env);
+    client.create_pool(
+        admin,
+        &pool_id,
+        &String::from_str(env, "USDC Lending Pool"),
+        &String::from_str(env, "USDC"),
+        &asset_address,
+        &PoolRiskParams {
+            collateral_factor: 750_000_000_000_000_000,
+            liquidation_threshold: 800_000_000_000_000_000,
+            liquidation_penalty: 50_000_000_000_000_000,
+            reserve_factor: 1000,
+        },
+    );
+    pool_id
+}
+
+#[test]
+fn test_deposit_and_withdraw() {
+    let (env, client, admin) = setup_registry();
+    let pool_id = create_test_pool(&env, &client, &admin);
+    let asset = client.get_pool(&pool_id).asset_address;
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&lender, &1_000_000);
+
+    let minted = client.deposit(&lender, &pool_id, &600_000);
+    assert_eq!(minted, 600_000);
+    let position = client.get_deposit(&lender, &pool_id).unwrap();
+    assert_eq!(position.shares, 600_000);
+    assert_eq!(
+        TokenClient::new(&env, &asset).balance(&client.address),
+        600_000
+    );
+
+    let burned = client.withdraw(&lender, &pool_id, &200_000);
+    assert_eq!(burned, 200_000);
+    assert_eq!(
+        client.get_deposit(&lender, &pool_id).unwrap().shares,
+        400_000
+    );
+
+    client.withdraw(&lender, &pool_id, &400_000);
+    assert!(client.get_deposit(&lender, &pool_id).is_none());
+    assert_eq!(TokenClient::new(&env, &asset).balance(&lender), 1_000_000);
+}
+
+#[test]
+fn test_withdraw_respects_available_liquidity() {
+    let (env, client, admin) = setup_registry();
+    let pool_id = create_test_pool(&env, &client, &admin);
+    let asset = client.get_pool(&pool_id).asset_address;
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&lender, &1_000_000);
+    client.deposit(&lender, &pool_id, &1_000_000);
+
+    env.as_contract(&client.address, || {
+        PoolStorage::set_total_borrows(&env, &pool_id, 900_000);
+    });
+    assert_eq!(
+        client.try_withdraw(&lender, &pool_id, &200_000),
+        Err(Ok(ContractError::InsufficientLiquidity.into()))
+    );
+}
+
+#[test]
+fn test_withdraw_from_empty_pool() {
+    let (env, client, admin) = setup_registry();
+    let pool_id = create_test_pool(&env, &client, &admin);
+    assert_eq!(
+        client.try_withdraw(&Address::generate(&env), &pool_id, &1),
+        Err(Ok(ContractError::InsufficientLiquidity.into()))
+    );
+}
+
+#[test]
+fn test_create_pool_requires_admin() {
+    let (env, client, _admin) = setup_registry();
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_create_pool(
+            &other,
+            &String::from_str(&env, "USDC-POOL"),
+            &String::from_str(&env, "USDC Lending Pool"),
+            &String::from_str(&env, "USDC"),
+            &create_payment_token(&env),
+            &PoolRiskParams {
+                collateral_factor: 750_000_000_000_000_000,
+                liquidation_threshold: 800_000_000_000_000_000,
+                liquidation_penalty: 50_000_000_000_000_000,
+                reserve_factor: 1000,
+            },
+        ),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+}
+
+// =========================================================================
+// Borrowing
+// =========================================================================
+
+fn setup_lending() -> (
+    Env,
+    PropertyTokenContractClient<'static>,
+    Address,
+    String,
+    Address,
+) {
+    let (env, client, admin) = setup_registry();
+    let pool_id = create_test_pool(&env, &client, &admin);
+    let asset = client.get_pool(&pool_id).asset_address;
+    client.set_price(&admin, &PriceFeed::Asset(asset.clone()), &PRECISION);
+
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&lender, &1_000_000);
+    client.deposit(&lender, &pool_id, &1_000_000);
+    (env, client, admin, pool_id, asset)
+}
+
+/// Registers and verifies a property worth 1_000 per share and posts its valuation
+fn register_collateral_property(
+    env: &Env,
+    client: &PropertyTokenContractClient,
+    admin: &Address,
+    owner: &Address,
+) -> u64 {
+    let property_id = client.register_property(
+        admin,
+        owner,
+        &String::from_str(env, "Loft"),
+        &String::from_str(env, "City loft"),
+        &String::from_str(env, "Lisbon"),
+        &1_000_000,
+        &1_000,
+    );
+    client.verify_property(admin, &property_id);
+    client.set_price(admin, &PriceFeed::Property(property_id), &1_000_000);
+    property_id
+}
+
+#[test]
+fn test_borrow_against_property_shares() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    assert_eq!(client.balance(&property_id, &borrower), 500);
+    assert_eq!(client.balance(&property_id, &client.address), 500);
+
+    let health_factor = client.borrow(&borrower, &pool_id, &300_000);
+    assert_eq!(health_factor, 500_000 * 800_000_000_000_000_000 / 300_000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&borrower), 300_000);
+
+    let position = client.get_borrow(&borrower, &pool_id).unwrap();
+    assert_eq!(position.principal, 300_000);
+    assert_eq!(position.collateral_amount, 500);
+    assert_eq!(position.collateral_property, Some(property_id));
+    assert_eq!(
+        client.get_price(&PriceFeed::Asset(asset)).unwrap().price,
+        PRECISION
+    );
+}
+
+#[test]
+fn test_borrow_above_collateral_factor() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    assert_eq!(
+        client.try_borrow(&borrower, &pool_id, &375_001),
+        Err(Ok(ContractError::InsufficientCollateral.into()))
+    );
+}
+
+#[test]
+fn test_borrow_against_whitelisted_token() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let collateral_token = create_payment_token(&env);
+    StellarAssetClient::new(&env, &collateral_token).mint(&borrower, &1_000);
+    client.set_price(
+        &admin,
+        &PriceFeed::Asset(collateral_token.clone()),
+        &(2 * PRECISION),
+    );
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetCollateralToken(collateral_token.clone(), true),
+    );
+
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::Token(collateral_token.clone()),
+        &1_000,
+    );
+    client.borrow(&borrower, &pool_id, &1_500);
+
+    let position = client.get_borrow(&borrower, &pool_id).unwrap();
+    assert_eq!(position.collateral_asset, collateral_token);
+    assert_eq!(position.collateral_property, None);
+}
+
+#[test]
+fn test_add_collateral_rejects_unlisted_token() {
+    let (env, client, _admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let collateral_token = create_payment_token(&env);
+
+    assert_eq!(
+        client.try_add_collateral(
+            &borrower,
+            &pool_id,
+            &Collateral::Token(collateral_token),
+            &1_000,
+        ),
+        Err(Ok(ContractError::CollateralNotAccepted.into()))
+    );
+}
+
+#[test]
+fn test_remove_collateral_keeps_position_safe() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+    assert_eq!(
+        client.try_remove_collateral(&borrower, &pool_id, &200),
+        Err(Ok(ContractError::InsufficientCollateral.into()))
+    );
+}
+
+// =========================================================================
+// Repayment
+// =========================================================================
+
+#[test]
+fn test_partial_and_full_repay_releases_collateral() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    assert_eq!(client.repay(&borrower, &pool_id, &100_000), 200_000);
+    assert_eq!(client.get_debt(&borrower, &pool_id), 200_000);
+    assert_eq!(client.balance(&property_id, &borrower), 500);
+
+    // Overpaying is capped at the outstanding debt
+    StellarAssetClient::new(&env, &asset).mint(&borrower, &50_000);
+    assert_eq!(client.repay(&borrower, &pool_id, &250_000), 0);
+    assert!(client.get_borrow(&borrower, &pool_id).is_none());
+    assert_eq!(client.balance(&property_id, &borrower), 1_000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&borrower), 50_000);
+}
+
+#[test]
+fn test_repay_splits_interest_between_lenders_and_reserves() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SECONDS_PER_YEAR);
+    let interest = client.accrue_interest(&pool_id);
+    assert!(interest > 0);
+    assert_eq!(client.get_debt(&borrower, &pool_id), 300_000 + interest);
+
+    StellarAssetClient::new(&env, &asset).mint(&borrower, &interest);
+    assert_eq!(client.repay(&borrower, &pool_id, &(300_000 + interest)), 0);
+
+    let reserves = interest / 10;
+    env.as_contract(&client.address, || {
+        assert_eq!(PoolStorage::get_reserves(&env, &pool_id), reserves);
+        assert_eq!(
+            PoolStorage::get_total_deposits(&env, &pool_id),
+            1_000_000 + interest - reserves
+        );
+        assert_eq!(PoolStorage::get_total_borrows(&env, &pool_id), 0);
+    });
+}
+
+// =========================================================================
+// Pool Caps and Reserves
+// =========================================================================
+
+#[test]
+fn test_supply_and_borrow_caps() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetPoolCaps(
+            pool_id.clone(),
+            PoolCaps {
+                supply_cap: 1_100_000,
+                borrow_cap: 200_000,
+                user_borrow_cap: 150_000,
+            },
+        ),
+    );
+    // One pool_updated event per changed cap, then operation_executed
+    assert_eq!(env.events().all().len(), 4);
+
+    let lender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&lender, &200_000);
+    assert_eq!(
+        client.try_deposit(&lender, &pool_id, &200_000),
+        Err(Ok(ContractError::CapExceeded.into()))
+    );
+    client.deposit(&lender, &pool_id, &100_000);
+
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    assert_eq!(
+        client.try_borrow(&borrower, &pool_id, &150_001),
+        Err(Ok(ContractError::CapExceeded.into()))
+    );
+    client.borrow(&borrower, &pool_id, &150_000);
+
+    let other = Address::generate(&env);
+    let other_property = register_collateral_property(&env, &client, &admin, &other);
+    client.add_collateral(
+        &other,
+        &pool_id,
+        &Collateral::PropertyShares(other_property),
+        &500,
+    );
+    assert_eq!(
+        client.try_borrow(&other, &pool_id, &60_000),
+        Err(Ok(ContractError::CapExceeded.into()))
+    );
+    client.borrow(&other, &pool_id, &50_000);
+
+    // Lifting the caps only reports the caps that changed
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetPoolCaps(
+            pool_id.clone(),
+            PoolCaps {
+                supply_cap: 1_100_000,
+                borrow_cap: 0,
+                user_borrow_cap: 0,
+            },
+        ),
+    );
+    assert_eq!(env.events().all().len(), 3);
+    client.borrow(&other, &pool_id, &100_000);
+}
+
+#[test]
+fn test_withdraw_reserves_to_treasury() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SECONDS_PER_YEAR);
+    let interest = client.accrue_interest(&pool_id);
+    let reserves = client.get_reserves(&pool_id);
+    assert_eq!(reserves, interest / 10);
+
+    StellarAssetClient::new(&env, &asset).mint(&borrower, &interest);
+    client.repay(&borrower, &pool_id, &(300_000 + interest));
+
+    let treasury = Address::generate(&env);
+    assert_eq!(
+        client.try_withdraw_reserves(&admin, &pool_id, &treasury, &(reserves + 1)),
+        Err(Ok(ContractError::InsufficientReserves.into()))
+    );
+    client.withdraw_reserves(&admin, &pool_id, &treasury, &reserves);
+    assert_eq!(env.events().all().len(), 2);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&treasury), reserves);
+    assert_eq!(client.get_reserves(&pool_id), 0);
+
+    // Lenders can still take out everything they are owed
+    assert_eq!(
+        TokenClient::new(&env, &asset).balance(&client.address),
+        1_000_000 + interest - reserves
+    );
+}
+
+#[test]
+fn test_withdraw_reserves_requires_admin() {
+    let (env, client, _admin, pool_id, _asset) = setup_lending();
+    let caller = Address::generate(&env);
+    assert_eq!(
+        client.try_withdraw_reserves(&caller, &pool_id, &caller, &1),
+        Err(Ok(ContractError::NotAdmin.into()))
+    );
+}
+
+// =========================================================================
+// Flash Loans
+// =========================================================================
+
+/// Receiver that pays back the loan and fee, or keeps the loan when `params`
+/// is non-empty
+#[contract]
+pub struct FlashBorrower;
+
+#[contractimpl]
+impl FlashBorrower {
+    pub fn __constructor(env: Env, lender: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("lender"), &lender);
+    }
+}
+
+#[contractimpl]
+impl FlashLoanReceiver for FlashBorrower {
+    fn on_flash_loan(env: Env, asset: Address, amount: i128, fee: i128, params: Bytes) {
+        if !params.is_empty() {
+            return;
+        }
+        let lender: Address = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("lender"))
+            .unwrap();
+        TokenClient::new(&env, &asset).transfer(
+            &env.current_contract_address(),
+            &lender,
+            &(amount + fee),
+        );
+    }
+}
+
+#[test]
+fn test_flash_loan_repaid_with_fee() {
+    let (env, client, _admin, pool_id, asset) = setup_lending();
+    let receiver = env.register(FlashBorrower, (client.address.clone(),));
+    StellarAssetClient::new(&env, &asset).mint(&receiver, &1_000);
+
+    // 0.09% of 1_000_000, split 90/10 between suppliers and reserves
+    let fee = client.flash_loan(&receiver, &pool_id, &1_000_000, &Bytes::new(&env));
+    assert_eq!(fee, 900);
+    // Both asset transfers and the flash loan event
+    assert_eq!(env.events().all().len(), 3);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&receiver), 100);
+    assert_eq!(client.get_reserves(&pool_id), 90);
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            PoolStorage::get_total_deposits(&env, &pool_id),
+            1_000_000 + 810
+        );
+    });
+}
+
+#[test]
+fn test_flash_loan_not_repaid() {
+    let (env, client, _admin, pool_id, asset) = setup_lending();
+    let receiver = env.register(FlashBorrower, (client.address.clone(),));
+    let params = Bytes::from_array(&env, &[1]);
+
+    assert_eq!(
+        client.try_flash_loan(&receiver, &pool_id, &1_000, &params),
+        Err(Ok(ContractError::FlashLoanNotRepaid.into()))
+    );
+    assert_eq!(
+        TokenClient::new(&env, &asset).balance(&client.address),
+        1_000_000
+    );
+}
+
+#[test]
+fn test_flash_loan_limited_to_available_liquidity() {
+    let (env, client, _admin, pool_id, _asset) = setup_lending();
+    let receiver = env.register(FlashBorrower, (client.address.clone(),));
+    assert_eq!(
+        client.try_flash_loan(&receiver, &pool_id, &1_000_001, &Bytes::new(&env)),
+        Err(Ok(ContractError::InsufficientLiquidity.into()))
+    );
+}
+
+// =========================================================================
+// Interest Accrual
+// =========================================================================
+
+#[test]
+fn test_accrue_interest_compounds_independently_of_frequency() {
+    let env = Env::default();
+    let rate = 100_000_000_000_000_000; // 10% APR
+    let one_step = InterestStorage::calculate_new_index(&env, PRECISION, rate, SECONDS_PER_YEAR);
+
+    let mut index = PRECISION;
+    for _ in 0..12 {
+        index = InterestStorage::calculate_new_index(&env, index, rate, SECONDS_PER_YEAR / 12);
+    }
+
+    // Both land near e^0.1 and within 0.01% of each other
+    assert!(one_step > PRECISION + PRECISION / 10);
+    assert!((one_step - index).abs() < PRECISION / 10_000);
+}
+
+#[test]
+fn test_accrue_interest_after_long_idle_period() {
+    let env = Env::default();
+
+    // 100% APR over five years compounds to about e^5
+    let index =
+        InterestStorage::calculate_new_index(&env, PRECISION, PRECISION, 5 * SECONDS_PER_YEAR);
+    let expected = 148_413_159_102_576_603_421; // e^5
+    assert!((index - expected).abs() < expected / 1_000);
+
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+    env.as_contract(&client.address, || {
+        InterestStorage::set_model(&env, &pool_id, &InterestRateModel::Fixed(PRECISION));
+    });
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 10 * SECONDS_PER_YEAR);
+    let interest = client.accrue_interest(&pool_id);
+    let expected = 6_607_639_738; // 300_000 * (e^10 - 1)
+    assert!((interest - expected).abs() < expected / 1_000);
+}
+
+#[test]
+fn test_accrue_interest_updates_pool_and_emits_event() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    // Nothing to accrue within the same ledger timestamp
+    assert_eq!(client.accrue_interest(&pool_id), 0);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400);
+    let interest = client.accrue_interest(&pool_id);
+    assert!(interest > 0);
+    assert_eq!(env.events().all().len(), 1);
+
+    env.as_contract(&client.address, || {
+        assert!(InterestStorage::get_interest_index(&env, &pool_id) > PRECISION);
+        assert_eq!(
+            InterestStorage::get_last_accrual(&env, &pool_id),
+            env.ledger().timestamp()
+        );
+        assert_eq!(
+            PoolStorage::get_total_borrows(&env, &pool_id),
+            300_000 + interest
+        );
+    });
+}
+
+// =========================================================================
+// Interest Rate Models
+// =========================================================================
+
+fn adaptive_model() -> AdaptiveRateModel {
+    AdaptiveRateModel {
+        target_utilization: 200_000_000_000_000_000, // 20%
+        rate_at_target: 40_000_000_000_000_000,      // 4%
+        min_rate: 10_000_000_000_000_000,            // 1%
+        max_rate: 200_000_000_000_000_000,           // 20%
+        adjustment_speed: 5 * PRECISION,
+    }
+}
+
+#[test]
+fn test_fixed_and_adaptive_rate_models() {
+    let fixed = InterestRateModel::Fixed(50_000_000_000_000_000);
+    assert_eq!(fixed.calculate_borrow_rate(0), 50_000_000_000_000_000);
+    assert_eq!(
+        fixed.calculate_borrow_rate(PRECISION),
+        50_000_000_000_000_000
+    );
+
+    let model = adaptive_model();
+    assert_eq!(
+        model.calculate_borrow_rate(model.target_utilization),
+        model.rate_at_target
+    );
+    assert_eq!(model.calculate_borrow_rate(0), model.rate_at_target / 2);
+    assert_eq!(
+        model.calculate_borrow_rate(PRECISION),
+        model.rate_at_target * 3 / 2
+    );
+
+    // Busier than target pushes the rate up, idler pulls it down
+    let mut busy = model.clone();
+    busy.adapt(PRECISION, 86_400 * 30);
+    assert!(busy.rate_at_target > model.rate_at_target);
+    let mut idle = model.clone();
+    idle.adapt(0, 86_400 * 30);
+    assert!(idle.rate_at_target < model.rate_at_target);
+
+    // Long periods stay within bounds
+    busy.adapt(PRECISION, SECONDS_PER_YEAR * 10);
+    assert_eq!(busy.rate_at_target, model.max_rate);
+}
+
+#[test]
+fn test_rate_model_switch_is_timelocked() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    set_timelock_delays(&client, &admin, 172_800);
+    let fixed = InterestRateModel::Fixed(100_000_000_000_000_000);
+
+    let operation_id = client.queue_operation(
+        &admin,
+        &TimelockOperation::SetRateModel(pool_id.clone(), fixed.clone()),
+    );
+    let eta = client.get_queued_operation(&operation_id).unwrap().eta;
+    assert_eq!(eta, env.ledger().timestamp() + 172_800);
+    assert_eq!(
+        client.try_execute_operation(&admin, &operation_id),
+        Err(Ok(ContractError::TooEarly.into()))
+    );
+
+    env.ledger().set_timestamp(eta);
+    client.execute_operation(&admin, &operation_id);
+    assert_eq!(client.get_rate_model(&pool_id), fixed);
+    assert_eq!(client.get_queued_operation(&operation_id), None);
+    assert_eq!(
+        client.get_current_rates(&pool_id).borrow_apr,
+        100_000_000_000_000_000
+    );
+}
+
+#[test]
+fn test_rate_model_rejects_invalid_parameters() {
+    let (_env, client, admin, pool_id, _asset) = setup_lending();
+    let mut model = adaptive_model();
+    model.rate_at_target = model.max_rate + 1;
+    assert_eq!(
+        client.try_queue_operation(
+            &admin,
+            &TimelockOperation::SetRateModel(pool_id, InterestRateModel::Adaptive(model)),
+        ),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+}
+
+#[test]
+fn test_adaptive_model_rises_above_target_utilization() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetRateModel(
+            pool_id.clone(),
+            InterestRateModel::Adaptive(adaptive_model()),
+        ),
+    );
+    let rates_before = client.get_current_rates(&pool_id);
+
+    // 30% utilization sits above the 20% target
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 86_400 * 30);
+    client.accrue_interest(&pool_id);
+    let rates_after = client.get_current_rates(&pool_id);
+    assert!(rates_after.borrow_apr > rates_before.borrow_apr);
+    match client.get_rate_model(&pool_id) {
+        InterestRateModel::Adaptive(model) => {
+            assert!(model.rate_at_target > adaptive_model().rate_at_target)
+        }
+        _ => panic!("Expected adaptive model"),
+    }
+}
+
+#[test]
+fn test_get_current_rates() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+
+    let rates = client.get_current_rates(&pool_id);
+    let model = JumpRateModel::default();
+    assert_eq!(rates.utilization, 300_000_000_000_000_000);
+    assert_eq!(
+        rates.borrow_apr,
+        model.calculate_borrow_rate(rates.utilization)
+    );
+    // 30% utilized, 10% of the interest goes to reserves
+    assert_eq!(rates.supply_apr, rates.borrow_apr * 3 / 10 * 9 / 10);
+    assert!(rates.borrow_apy > rates.borrow_apr);
+    assert!(rates.supply_apy > rates.supply_apr);
+    assert!(rates.supply_apy < rates.borrow_apy);
+}
+
+// =========================================================================
+// Liquidation
+// =========================================================================
+
+fn setup_unhealthy_position(
+    valuation_after_drop: i128,
+) -> (
+    Env,
+    PropertyTokenContractClient<'static>,
+    String,
+    Address,
+    Address,
+    u64,
+) {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &375_000);
+
+    // Allow sharp crashes so the valuation can fall in a single report
+    run_operation(
+        &client,
+        &admin,
+        TimelockOperation::SetOracleConfig(OracleConfig {
+            max_age: 86_400,
+            max_deviation_bps: 9_500,
+        }),
+    );
+    client.set_price(
+        &admin,
+        &PriceFeed::Property(property_id),
+        &valuation_after_drop,
+    );
+
+    let liquidator = Address::generate(&env);
+    grant_role(&client, &admin, &liquidator, Role::Liquidator);
+    StellarAssetClient::new(&env, &asset).mint(&liquidator, &1_000_000);
+    (env, client, pool_id, borrower, liquidator, property_id)
+}
+
+#[test]
+fn test_liquidate_up_to_close_factor() {
+    let (_env, client, pool_id, borrower, liquidator, property_id) =
+        setup_unhealthy_position(800_000);
+
+    let seized = client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000);
+    assert_eq!(seized, 246);
+    assert_eq!(client.balance(&property_id, &liquidator), 246);
+
+    let position = client.get_borrow(&borrower, &pool_id).unwrap();
+    assert_eq!(position.principal, 187_500);
+    assert_eq!(position.collateral_amount, 254);
+    assert_eq!(client.get_bad_debt(&pool_id), 0);
+}
+
+#[test]
+fn test_liquidate_records_bad_debt() {
+    let (env, client, pool_id, borrower, liquidator, property_id) =
+        setup_unhealthy_position(100_000);
+
+    let seized = client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000);
+    assert_eq!(seized, 500);
+    assert_eq!(client.balance(&property_id, &liquidator), 500);
+    assert!(client.get_borrow(&borrower, &pool_id).is_none());
+    assert_eq!(client.get_bad_debt(&pool_id), 375_000 - 47_619);
+    env.as_contract(&client.address, || {
+        assert_eq!(PoolStorage::get_total_borrows(&env, &pool_id), 0);
+    });
+}
+
+#[test]
+fn test_insurance_fund_absorbs_bad_debt() {
+    let (env, client, pool_id, borrower, liquidator, _property_id) =
+        setup_unhealthy_position(100_000);
+    let funder = Address::generate(&env);
+    let asset = client.get_pool(&pool_id).asset_address;
+    StellarAssetClient::new(&env, &asset).mint(&funder, &400_000);
+    client.fund_insurance(&funder, &pool_id, &400_000);
+
+    client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000);
+    let bad_debt = 375_000 - 47_619;
+    assert_eq!(client.get_bad_debt(&pool_id), bad_debt);
+    assert_eq!(client.get_insurance_fund(&pool_id), 400_000 - bad_debt);
+    assert_eq!(client.receipt_exchange_rate(&pool_id), PRECISION);
+}
+
+#[test]
+fn test_bad_debt_beyond_insurance_is_socialized() {
+    let (env, client, pool_id, borrower, liquidator, _property_id) =
+        setup_unhealthy_position(100_000);
+    let funder = Address::generate(&env);
+    let asset = client.get_pool(&pool_id).asset_address;
+    StellarAssetClient::new(&env, &asset).mint(&funder, &100_000);
+    client.fund_insurance(&funder, &pool_id, &100_000);
+
+    client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000);
+    let socialized = 375_000 - 47_619 - 100_000;
+    assert_eq!(client.get_insurance_fund(&pool_id), 0);
+    // 1_000_000 supplied for 1_000_000 receipts, minus the written-down shortfall
+    assert_eq!(
+        client.receipt_exchange_rate(&pool_id),
+        (1_000_000 - socialized) * PRECISION / 1_000_000
+    );
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            PoolStorage::get_total_deposits(&env, &pool_id),
+            1_000_000 - socialized
+        );
+    });
+}
+
+#[test]
+fn test_reserves_slice_finances_insurance_fund() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let mut config = client.get_lending_config();
+    config.insurance_share_bps = 5_000;
+    run_operation(&client, &admin, TimelockOperation::SetLendingConfig(config));
+
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SECONDS_PER_YEAR);
+    let interest = client.accrue_interest(&pool_id);
+
+    let reserves = interest / 10;
+    assert_eq!(client.get_insurance_fund(&pool_id), reserves / 2);
+    assert_eq!(client.get_reserves(&pool_id), reserves - reserves / 2);
+}
+
+#[test]
+fn test_liquidate_healthy_position() {
+    let (_env, client, pool_id, borrower, liquidator, _property_id) =
+        setup_unhealthy_position(1_000_000);
+    assert_eq!(
+        client.try_liquidate(&liquidator, &borrower, &pool_id, &pool_id, &100_000),
+        Err(Ok(ContractError::PositionHealthy.into()))
+    );
+}
+
+#[test]
+fn test_liquidate_requires_liquidator_role() {
+    let (_env, client, pool_id, borrower, _liquidator, _property_id) =
+        setup_unhealthy_position(800_000);
+    assert_eq!(
+        client.try_liquidate(&borrower, &borrower, &pool_id, &pool_id, &100_000),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+}
+
+// =========================================================================
+// Granular Pause Controls
+// =========================================================================
+
+#[test]
+fn test_pool_pause_freezes_borrows_but_not_repay_or_liquidation() {
+    let (env, client, pool_id, borrower, liquidator, _property_id) =
+        setup_unhealthy_position(800_000);
+    let admin = client.get_admin().unwrap();
+    let pauser = Address::generate(&env);
+    grant_role(&client, &admin, &pauser, Role::Pauser);
+
+    client.set_pool_paused_functions(&pauser, &pool_id, &(PAUSE_DEPOSIT | PAUSE_BORROW));
+    assert_eq!(
+        client.get_pool_paused_functions(&pool_id),
+        PAUSE_DEPOSIT | PAUSE_BORROW
+    );
+    assert_eq!(
+        client.try_borrow(&borrower, &pool_id, &1),
+        Err(Ok(ContractError::FunctionPaused.into()))
+    );
+    assert_eq!(
+        client.try_deposit(&liquidator, &pool_id, &1_000),
+        Err(Ok(ContractError::FunctionPaused.into()))
+    );
+
+    client.liquidate(&liquidator, &borrower, &pool_id, &pool_id, &1_000_000);
+    assert_eq!(client.repay(&borrower, &pool_id, &100_000), 87_500);
+
+    client.set_pool_paused_functions(&pauser, &pool_id, &PAUSE_REPAY);
+    assert_eq!(
+        client.try_repay(&borrower, &pool_id, &1_000),
+        Err(Ok(ContractError::FunctionPaused.into()))
+    );
+    assert_eq!(
+        client.try_set_paused_functions(&pauser, &(1 << 7)),
+        Err(Ok(ContractError::InvalidParameters.into()))
+    );
+}
+
+#[test]
+fn test_guardian_can_only_add_pause_flags() {
+    let (env, client, admin) = setup_registry();
+    let guardian = Address::generate(&env);
+    grant_role(&client, &admin, &guardian, Role::Guardian);
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+
+    let usdc = create_payment_token(&env);
+    StellarAssetClient::new(&env, &usdc).mint(&owner, &10_000);
+    client.distribute_dividends(&owner, &property_id, &usdc, &10_000);
+
+    client.set_paused_functions(&guardian, &PAUSE_DIVIDEND_CLAIM);
+    assert_eq!(client.get_paused_functions(), PAUSE_DIVIDEND_CLAIM);
+    assert_eq!(
+        client.try_claim_dividends(&owner, &property_id),
+        Err(Ok(ContractError::FunctionPaused.into()))
+    );
+    client.transfer(&property_id, &owner, &investor, &250);
+
+    assert_eq!(
+        client.try_set_paused_functions(&guardian, &0),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    client.set_paused_functions(&admin, &0);
+    assert_eq!(client.claim_dividends(&owner, &property_id), 10_000);
+}
+
+#[test]
+fn test_property_pause_blocks_only_that_property() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let investor = Address::generate(&env);
+    let paused_property = register_test_property(&env, &client, &owner, 1_000);
+    let other_property = register_test_property(&env, &client, &owner, 1_000);
+
+    assert_eq!(
+        client.try_set_property_paused_functions(
+            &investor,
+            &paused_property,
+            &PAUSE_SHARE_TRANSFER
+        ),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    client.set_property_paused_functions(&admin, &paused_property, &PAUSE_SHARE_TRANSFER);
+    assert_eq!(
+        client.get_property_paused_functions(&paused_property),
+        PAUSE_SHARE_TRANSFER
+    );
+    env.as_contract(&client.address, || {
+        let key = keys::StorageKey::PropertyPaused(paused_property);
+        assert!(!env.storage().instance().has(&key));
+        assert!(env.storage().persistent().get_ttl(&key) >= 2_592_000);
+    });
+
+    assert_eq!(
+        client.try_transfer(&paused_property, &owner, &investor, &250),
+        Err(Ok(ContractError::FunctionPaused.into()))
+    );
+    client.transfer(&other_property, &owner, &investor, &250);
+    assert_eq!(client.balance(&other_property, &investor), 250);
+}
+
+// =========================================================================
+// Account Health
+// =========================================================================
+
+/// Creates a second funded pool priced at 1.0 next to the one from `setup_lending`
+fn create_second_pool(
+    env: &Env,
+    client: &PropertyTokenContractClient,
+    admin: &Address,
+) -> (String, Address) {
+    let pool_id = String::from_str(env, "EURC-POOL");
+    let asset = create_payment_token(env);
+    client.create_pool(
+        admin,
+        &pool_id,
+        &String::from_str(env, "EURC Lending Pool"),
+        &String::from_str(env, "EURC"),
+        &asset,
+        &PoolRiskParams {
+            collateral_factor: 700_000_000_000_000_000,
+            liquidation_threshold: 750_000_000_000_000_000,
+            liquidation_penalty: 100_000_000_000_000_000,
+            reserve_factor: 1000,
+        },
+    );
+    client.set_price(admin, &PriceFeed::Asset(asset.clone()), &PRECISION);
+
+    let lender = Address::generate(env);
+    StellarAssetClient::new(env, &asset).mint(&lender, &1_000_000);
+    client.deposit(&lender, &pool_id, &1_000_000);
+    (pool_id, asset)
+}
+
+#[test]
+fn test_collateral_in_one_pool_backs_debt_in_another() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let (other_pool_id, other_asset) = create_second_pool(&env, &client, &admin);
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+
+    // 500_000 of collateral at a 75% collateral factor
+    assert_eq!(client.max_borrowable(&borrower, &other_pool_id), 375_000);
+
+    let health_factor = client.borrow(&borrower, &other_pool_id, &200_000);
+    assert_eq!(health_factor, 2 * PRECISION);
+    assert_eq!(
+        TokenClient::new(&env, &other_asset).balance(&borrower),
+        200_000
+    );
+    assert_eq!(client.get_account_health(&borrower), 2 * PRECISION);
+    assert_eq!(
+        client.get_account_summary(&borrower),
+        AccountSummary {
+            total_supplied_value: 0,
+            total_collateral_value: 500_000,
+            total_debt_value: 200_000,
+            borrow_capacity: 375_000,
+            liquidation_capacity: 400_000,
+            health_factor: 2 * PRECISION,
+        }
+    );
+    assert_eq!(client.max_borrowable(&borrower, &pool_id), 175_000);
+}
+
+#[test]
+fn test_remove_collateral_backing_debt_in_another_pool() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let (other_pool_id, _other_asset) = create_second_pool(&env, &client, &admin);
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &other_pool_id, &200_000);
+
+    assert_eq!(
+        client.try_remove_collateral(&borrower, &pool_id, &500),
+        Err(Ok(ContractError::InsufficientCollateral.into()))
+    );
+}
+
+#[test]
+fn test_liquidate_across_pools() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let (other_pool_id, other_asset) = create_second_pool(&env, &client, &admin);
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &other_pool_id, &375_000);
+    client.set_price(&admin, &PriceFeed::Property(property_id), &800_000);
+    assert!(client.get_account_health(&borrower) < PRECISION);
+
+    let liquidator = Address::generate(&env);
+    grant_role(&client, &admin, &liquidator, Role::Liquidator);
+    StellarAssetClient::new(&env, &other_asset).mint(&liquidator, &1_000_000);
+
+    // Half the debt repaid, collateral seized with the collateral pool's 5% penalty
+    let seized = client.liquidate(&liquidator, &borrower, &other_pool_id, &pool_id, &1_000_000);
+    assert_eq!(seized, 246);
+    assert_eq!(client.get_debt(&borrower, &other_pool_id), 187_500);
+    assert_eq!(
+        client
+            .get_borrow(&borrower, &pool_id)
+            .unwrap()
+            .collateral_amount,
+        254
+    );
+}
+
+// =========================================================================
+// Deposit Receipts
+// =========================================================================
+
+#[test]
+fn test_receipts_transfer_and_grow_with_interest() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let depositor = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&depositor, &100_000);
+    let receipts = client.deposit(&depositor, &pool_id, &100_000);
+    assert_eq!(client.receipt_balance(&pool_id, &depositor), receipts);
+    assert_eq!(client.receipt_exchange_rate(&pool_id), PRECISION);
+
+    client.receipt_transfer(&pool_id, &depositor, &buyer, &(receipts / 2));
+    assert_eq!(client.receipt_balance(&pool_id, &buyer), receipts / 2);
+    assert_eq!(client.get_deposit(&buyer, &pool_id).unwrap().amount, 50_000);
+    assert_eq!(
+        client.try_receipt_transfer(&pool_id, &buyer, &depositor, &(receipts / 2 + 1)),
+        Err(Ok(ContractError::InsufficientBalance.into()))
+    );
+
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+    client.borrow(&borrower, &pool_id, &300_000);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + SECONDS_PER_YEAR);
+    client.accrue_interest(&pool_id);
+    assert!(client.receipt_exchange_rate(&pool_id) > PRECISION);
+
+    // Burning all receipts pays out the position plus its interest
+    let withdrawn = client.redeem(&buyer, &pool_id, &(receipts / 2));
+    assert!(withdrawn > 50_000);
+    assert_eq!(TokenClient::new(&env, &asset).balance(&buyer), withdrawn);
+    assert_eq!(client.receipt_balance(&pool_id, &buyer), 0);
+    assert!(client.get_deposit(&buyer, &pool_id).is_none());
+}
+
+#[test]
+fn test_receipts_transfer_from_with_allowance() {
+    let (env, client, _admin, pool_id, asset) = setup_lending();
+    let depositor = Address::generate(&env);
+    let spender = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&depositor, &100_000);
+    client.deposit(&depositor, &pool_id, &100_000);
+
+    client.receipt_approve(&pool_id, &depositor, &spender, &40_000, &1_000);
+    assert_eq!(
+        client.receipt_allowance(&pool_id, &depositor, &spender),
+        40_000
+    );
+    client.receipt_transfer_from(&pool_id, &spender, &depositor, &spender, &30_000);
+    assert_eq!(
+        client.receipt_allowance(&pool_id, &depositor, &spender),
+        10_000
+    );
+    assert_eq!(
+        client.try_receipt_transfer_from(&pool_id, &spender, &depositor, &spender, &20_000),
+        Err(Ok(ContractError::InsufficientAllowance.into()))
+    );
+    assert_eq!(client.receipt_balance(&pool_id, &spender), 30_000);
+
+    // Zero and negative amounts are rejected before the allowance is read
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_receipt_transfer_from(&pool_id, &stranger, &depositor, &stranger, &0),
+        Err(Ok(ContractError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_receipt_transfer_from(&pool_id, &spender, &depositor, &spender, &-5_000),
+        Err(Ok(ContractError::InvalidAmount.into()))
+    );
+}
+
+#[test]
+fn test_receipts_as_collateral() {
+    let (env, client, admin, pool_id, asset) = setup_lending();
+    let (other_pool_id, other_asset) = create_second_pool(&env, &client, &admin);
+    let borrower = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&borrower, &100_000);
+    let receipts = client.deposit(&borrower, &pool_id, &100_000);
+
+    client.add_collateral(
+        &borrower,
+        &other_pool_id,
+        &Collateral::PoolReceipts(pool_id.clone()),
+        &receipts,
+    );
+    let position = client.get_borrow(&borrower, &other_pool_id).unwrap();
+    assert_eq!(position.collateral_pool, Some(pool_id.clone()));
+    assert_eq!(client.receipt_balance(&pool_id, &borrower), 0);
+    assert_eq!(client.receipt_balance(&pool_id, &client.address), receipts);
+
+    // 100_000 of receipts at the EURC pool's 70% collateral factor
+    assert_eq!(client.max_borrowable(&borrower, &other_pool_id), 70_000);
+    client.borrow(&borrower, &other_pool_id, &70_000);
+    assert_eq!(
+        client.try_remove_collateral(&borrower, &other_pool_id, &1),
+        Err(Ok(ContractError::InsufficientCollateral.into()))
+    );
+
+    client.repay(&borrower, &other_pool_id, &70_000);
+    assert_eq!(client.receipt_balance(&pool_id, &borrower), receipts);
+    assert_eq!(TokenClient::new(&env, &other_asset).balance(&borrower), 0);
+}
+
+// =========================================================================
+// Oracle
+// =========================================================================
+
+#[test]
+fn test_oracle_median_of_reports() {
+    let (env, client, admin) = setup_registry();
+    let feed = PriceFeed::Asset(Address::generate(&env));
+    let oracles = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for oracle in oracles.iter() {
+        grant_role(&client, &admin, oracle, Role::Oracle);
+    }
+
+    // Only the admin can seed a token feed
+    assert_eq!(
+        client.try_set_price(&oracles[0], &feed, &100),
+        Err(Ok(ContractError::Unauthorized.into()))
+    );
+    client.set_price(&admin, &feed, &104);
+
+    client.set_price(&oracles[0], &feed, &100);
+    client.set_price(&oracles[1], &feed, &110);
+    client.set_price(&oracles[2], &feed, &104);
+    assert_eq!(client.get_price(&feed).unwrap().price, 104);
+
+    // An oracle's new report replaces its previous one
+    client.set_price(&oracles[0], &feed, &112);
+    assert_eq!(client.get_price_reports(&feed).len(), 4);
+    assert_eq!(client.get_price(&feed).unwrap().price, 107);
+
+    // Reports of revoked oracles are ignored
+    client.revoke_role(&admin, &oracles[2], &Role::Oracle);
+    assert_eq!(client.get_price(&feed).unwrap().price, 110);
+}
+
+#[test]
+fn test_oracle_property_price_bounded_by_valuation() {
+    let (env, client, admin) = setup_registry();
+    let owner = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    grant_role(&client, &admin, &oracle, Role::Oracle);
+    let property_id = register_test_property(&env, &client, &owner, 1_000);
+    let feed = PriceFeed::Property(property_id);
+
+    // Even the first report must stay near the registry valuation
+    assert_eq!(
+        client.try_set_price(&oracle, &feed, &1),
+        Err(Ok(ContractError::PriceDeviationTooLarge.into()))
+    );
+    client.set_price(&oracle, &feed, &11_000_000_000_000);
+    client.set_price(&oracle, &feed, &12_000_000_000_000);
+    assert_eq!(
+        client.try_set_price(&oracle, &feed, &13_000_000_000_000),
+        Err(Ok(ContractError::PriceDeviationTooLarge.into()))
+    );
+
+    // A valuation signed off in the registry moves the band
+    let verifier = Address::generate(&env);
+    grant_role(&client, &admin, &verifier, Role::Verifier);
+    client.propose_valuation(&owner, &property_id, &14_000_000_000_000, &None);
+    client.approve_valuation(&verifier, &property_id, &14_000_000_000_000);
+    client.set_price(&oracle, &feed, &13_000_000_000_000);
+    assert_eq!(client.get_price(&feed).unwrap().price, 13_000_000_000_000);
+}
+
+#[test]
+fn test_oracle_rejects_large_deviation() {
+    let (env, client, admin) = setup_registry();
+    let feed = PriceFeed::Asset(Address::generate(&env));
+
+    client.set_price(&admin, &feed, &1_000);
+    assert_eq!(
+        client.try_set_price(&admin, &feed, &1_201),
+        Err(Ok(ContractError::PriceDeviationTooLarge.into()))
+    );
+}
+
+#[test]
+fn test_oracle_deviation_guard_outlives_stale_reports() {
+    let (env, client, admin) = setup_registry();
+    let feed = PriceFeed::Asset(Address::generate(&env));
+    let oracle = Address::generate(&env);
+    grant_role(&client, &admin, &oracle, Role::Oracle);
+    client.set_price(&admin, &feed, &1_000);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + client.get_oracle_config().max_age + 1);
+    assert!(client.get_price(&feed).is_none());
+    assert_eq!(
+        client.try_set_price(&oracle, &feed, &5_000),
+        Err(Ok(ContractError::PriceDeviationTooLarge.into()))
+    );
+
+    client.set_price(&oracle, &feed, &1_100);
+    assert_eq!(client.get_price(&feed).unwrap().price, 1_100);
+}
+
+#[test]
+fn test_borrow_rejects_stale_price() {
+    let (env, client, admin, pool_id, _asset) = setup_lending();
+    let borrower = Address::generate(&env);
+    let property_id = register_collateral_property(&env, &client, &admin, &borrower);
+    client.add_collateral(
+        &borrower,
+        &pool_id,
+        &Collateral::PropertyShares(property_id),
+        &500,
+    );
+
+    env.ledger()
+        .set_timestamp(client.get_oracle_config().max_age + 1);
+    assert!(client
+        .get_price(&PriceFeed::Property(property_id))
+        .is_none());
+    assert_eq!(
+        client.try_borrow(&borrower, &pool_id, &100_000),
+        Err(Ok(ContractError::PriceStale.into()))
+    );
+}
@@ -0,0 +1,125 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use super::operation::{QueuedOperation, TimelockConfig, TimelockOperation, TimelockStorage};
+use crate::access::{AdminControl, Role, RoleStorage};
+use crate::errors::ContractError;
+use crate::events::TimelockEvents;
+use crate::lending::{CollateralStorage, LendingConfigStorage, LendingControl, PoolStorage};
+use crate::market::MarketStorage;
+use crate::oracle::OracleStorage;
+
+/// Timelocked admin operations
+///
+/// The admin queues and executes operations; the admin or a guardian can
+/// cancel them while they wait.
+pub struct TimelockControl;
+
+impl TimelockControl {
+    /// Set every delay to a non-zero minimum that later changes cannot undercut
+    pub fn initialize(env: &Env, min_delay: u64) {
+        if min_delay == 0 {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+        TimelockStorage::set_min_delay(env, min_delay);
+        TimelockStorage::set_config(env, &TimelockConfig::uniform(min_delay));
+    }
+
+    /// Validate and queue an operation
+    ///
+    /// # Returns
+    /// * `u64` - ID of the queued operation
+    pub fn queue(env: &Env, operation: TimelockOperation) -> u64 {
+        match &operation {
+            TimelockOperation::SetPoolRiskParams(pool_id, params) => {
+                PoolStorage::get(env, pool_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+                LendingControl::validate_risk_params(env, params);
+            }
+            TimelockOperation::SetRateModel(pool_id, model) => {
+                PoolStorage::get(env, pool_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+                model.validate(env);
+            }
+            TimelockOperation::SetPoolCaps(pool_id, _) => {
+                PoolStorage::get(env, pool_id)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::PoolNotFound));
+            }
+            TimelockOperation::GrantRole(_, role) if *role == Role::Admin => {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            TimelockOperation::SetConfig(config)
+                if config.min_delay() < TimelockStorage::get_min_delay(env) =>
+            {
+                panic_with_error!(env, ContractError::InvalidParameters)
+            }
+            _ => {}
+        }
+
+        let config = TimelockStorage::get_config(env);
+        let queued = QueuedOperation {
+            id: TimelockStorage::next_id(env),
+            eta: env.ledger().timestamp() + operation.delay(&config),
+            operation,
+        };
+        TimelockStorage::set_operation(env, &queued);
+
+        TimelockEvents::operation_queued(env, queued.id, queued.operation.clone(), queued.eta);
+        queued.id
+    }
+
+    /// Apply a queued operation once its delay has passed
+    pub fn execute(env: &Env, operation_id: u64) {
+        let queued = TimelockStorage::get_operation(env, operation_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        if env.ledger().timestamp() < queued.eta {
+            panic_with_error!(env, ContractError::TooEarly);
+        }
+        TimelockStorage::remove_operation(env, operation_id);
+
+        match &queued.operation {
+            TimelockOperation::SetPoolRiskParams(pool_id, params) => {
+                LendingControl::set_risk_params(env, pool_id, params);
+            }
+            TimelockOperation::SetPoolCaps(pool_id, caps) => {
+                LendingControl::set_pool_caps(env, pool_id, caps.clone());
+            }
+            TimelockOperation::SetCollateralToken(token, enabled) => {
+                CollateralStorage::set_whitelisted(env, token, *enabled);
+            }
+            TimelockOperation::SetRateModel(pool_id, model) => {
+                LendingControl::set_rate_model(env, pool_id, model);
+            }
+            TimelockOperation::GrantRole(account, role) => {
+                RoleStorage::grant_role(env, account, role);
+            }
+            TimelockOperation::TransferAdmin(new_admin) => {
+                let admin = AdminControl::get_admin(env)
+                    .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized));
+                AdminControl::transfer_admin_start(env, &admin, new_admin);
+            }
+            TimelockOperation::SetLendingConfig(config) => {
+                LendingConfigStorage::set(env, config);
+            }
+            TimelockOperation::SetMarketConfig(config) => {
+                MarketStorage::set_config(env, config);
+            }
+            TimelockOperation::SetOracleConfig(config) => {
+                OracleStorage::set_config(env, config);
+            }
+            TimelockOperation::SetConfig(config) => {
+                TimelockStorage::set_config(env, config);
+            }
+        }
+
+        TimelockEvents::operation_executed(env, operation_id);
+    }
+
+    /// Drop a queued operation
+    pub fn cancel(env: &Env, caller: &Address, operation_id: u64) {
+        TimelockStorage::get_operation(env, operation_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::NotFound));
+        TimelockStorage::remove_operation(env, operation_id);
+
+        TimelockEvents::operation_cancelled(env, operation_id, caller.clone());
+    }
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::contracttype;
+
+/// Storage key types for the timelock module
+#[derive(Clone)]
+#[contracttype]
+pub enum TimelockKey {
+    /// Delay applied to each kind of operation
+    /// Storage: Instance
+    TimelockConfig,
+
+    /// Floor for every delay, fixed at initialization
+    /// Storage: Instance
+    MinDelay,
+
+    /// Queued operation by operation ID
+    /// Storage: Persistent
+    Operation(u64),
+
+    /// Counter used to generate operation IDs
+    /// Storage: Instance
+    OperationCounter,
+}
+
+/// TTL bump amounts for timelock storage
+pub mod timelock_bump {
+    pub const PERSISTENT_BUMP: u32 = 2592000; // ~300 days
+}
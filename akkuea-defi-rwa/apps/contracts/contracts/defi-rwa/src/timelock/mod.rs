@@ -0,0 +1,12 @@
+// Timelock module for DeFi RWA
+// This module delays sensitive admin changes (pool risk parameters and caps, collateral
+// whitelist, interest rate models, lending/market/oracle configuration, role grants and
+// admin transfer) so users can react before they take effect.
+
+mod control;
+mod keys;
+mod operation;
+
+pub use control::TimelockControl;
+pub use keys::{timelock_bump, TimelockKey};
+pub use operation::{QueuedOperation, TimelockConfig, TimelockOperation, TimelockStorage};
@@ -0,0 +1,186 @@
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+use super::keys::{timelock_bump, TimelockKey};
+use crate::access::Role;
+use crate::errors::ContractError;
+use crate::lending::{InterestRateModel, LendingConfig, PoolCaps, PoolRiskParams};
+use crate::market::MarketConfig;
+use crate::oracle::OracleConfig;
+
+/// Seconds each kind of operation waits between queuing and execution
+///
+/// Every delay starts at the minimum delay chosen at initialization and can
+/// never be lowered below it.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct TimelockConfig {
+    /// Delay for pool risk parameter changes
+    pub risk_params_delay: u64,
+    /// Delay for interest rate model swaps
+    pub rate_model_delay: u64,
+    /// Delay for role grants
+    pub role_grant_delay: u64,
+    /// Delay for admin transfers
+    pub admin_transfer_delay: u64,
+    /// Delay for lending, market and oracle configuration changes
+    pub config_delay: u64,
+}
+
+impl TimelockConfig {
+    /// Same delay for every kind of operation
+    pub fn uniform(delay: u64) -> Self {
+        TimelockConfig {
+            risk_params_delay: delay,
+            rate_model_delay: delay,
+            role_grant_delay: delay,
+            admin_transfer_delay: delay,
+            config_delay: delay,
+        }
+    }
+
+    /// Longest configured delay
+    pub fn max_delay(&self) -> u64 {
+        self.risk_params_delay
+            .max(self.rate_model_delay)
+            .max(self.role_grant_delay)
+            .max(self.admin_transfer_delay)
+            .max(self.config_delay)
+    }
+
+    /// Shortest configured delay
+    pub fn min_delay(&self) -> u64 {
+        self.risk_params_delay
+            .min(self.rate_model_delay)
+            .min(self.role_grant_delay)
+            .min(self.admin_transfer_delay)
+            .min(self.config_delay)
+    }
+}
+
+/// Sensitive change that has to wait out a delay
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TimelockOperation {
+    /// Replace a pool's risk parameters
+    SetPoolRiskParams(String, PoolRiskParams),
+    /// Replace a pool's supply and borrow caps
+    SetPoolCaps(String, PoolCaps),
+    /// Add (`true`) or remove a token from the collateral whitelist
+    SetCollateralToken(Address, bool),
+    /// Switch a pool to another interest rate model
+    SetRateModel(String, InterestRateModel),
+    /// Grant a role to an account
+    GrantRole(Address, Role),
+    /// Nominate a new admin, who then has to accept
+    TransferAdmin(Address),
+    /// Replace the protocol-wide lending configuration
+    SetLendingConfig(LendingConfig),
+    /// Replace the secondary market configuration
+    SetMarketConfig(MarketConfig),
+    /// Replace the oracle staleness and deviation guards
+    SetOracleConfig(OracleConfig),
+    /// Change the delays themselves
+    SetConfig(TimelockConfig),
+}
+
+impl TimelockOperation {
+    /// Delay this operation has to wait
+    ///
+    /// Changing the delays waits for the longest current delay, so lowering
+    /// one can never shortcut a change already covered by it.
+    pub fn delay(&self, config: &TimelockConfig) -> u64 {
+        match self {
+            TimelockOperation::SetPoolRiskParams(..)
+            | TimelockOperation::SetPoolCaps(..)
+            | TimelockOperation::SetCollateralToken(..) => config.risk_params_delay,
+            TimelockOperation::SetRateModel(..) => config.rate_model_delay,
+            TimelockOperation::GrantRole(..) => config.role_grant_delay,
+            TimelockOperation::TransferAdmin(_) => config.admin_transfer_delay,
+            TimelockOperation::SetLendingConfig(_)
+            | TimelockOperation::SetMarketConfig(_)
+            | TimelockOperation::SetOracleConfig(_) => config.config_delay,
+            TimelockOperation::SetConfig(_) => config.max_delay(),
+        }
+    }
+}
+
+/// Operation waiting in the timelock queue
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct QueuedOperation {
+    /// Unique operation identifier
+    pub id: u64,
+    /// Change to apply
+    pub operation: TimelockOperation,
+    /// Timestamp from which the operation can be executed
+    pub eta: u64,
+}
+
+/// Timelock storage helpers
+pub struct TimelockStorage;
+
+impl TimelockStorage {
+    /// Get timelock configuration
+    pub fn get_config(env: &Env) -> TimelockConfig {
+        env.storage()
+            .instance()
+            .get(&TimelockKey::TimelockConfig)
+            .unwrap_or_default()
+    }
+
+    /// Store timelock configuration
+    pub fn set_config(env: &Env, config: &TimelockConfig) {
+        env.storage()
+            .instance()
+            .set(&TimelockKey::TimelockConfig, config);
+    }
+
+    /// Get the floor no delay can be lowered below
+    pub fn get_min_delay(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&TimelockKey::MinDelay)
+            .unwrap_or(0)
+    }
+
+    /// Store the floor no delay can be lowered below
+    pub fn set_min_delay(env: &Env, min_delay: u64) {
+        env.storage()
+            .instance()
+            .set(&TimelockKey::MinDelay, &min_delay);
+    }
+
+    /// Generate the next operation ID
+    pub fn next_id(env: &Env) -> u64 {
+        let key = TimelockKey::OperationCounter;
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = current
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::MathOverflow));
+        env.storage().instance().set(&key, &next);
+        next
+    }
+
+    /// Store queued operation
+    pub fn set_operation(env: &Env, queued: &QueuedOperation) {
+        let key = TimelockKey::Operation(queued.id);
+        env.storage().persistent().set(&key, queued);
+        env.storage().persistent().extend_ttl(
+            &key,
+            timelock_bump::PERSISTENT_BUMP,
+            timelock_bump::PERSISTENT_BUMP,
+        );
+    }
+
+    /// Get queued operation by ID
+    pub fn get_operation(env: &Env, operation_id: u64) -> Option<QueuedOperation> {
+        let key = TimelockKey::Operation(operation_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Drop a queued operation
+    pub fn remove_operation(env: &Env, operation_id: u64) {
+        let key = TimelockKey::Operation(operation_id);
+        env.storage().persistent().remove(&key);
+    }
+}
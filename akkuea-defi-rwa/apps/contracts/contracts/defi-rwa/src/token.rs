@@ -0,0 +1,169 @@
+//! Share token module
+//!
+//! Exposes the shares of every tokenized property through the SEP-41 token
+//! interface. A single contract manages all properties, so each token function
+//! is scoped by the property ID it operates on.
+
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::access::PauseControl;
+use crate::buyout::BuyoutStorage;
+use crate::compliance::ComplianceControl;
+use crate::dividends::DividendControl;
+use crate::errors::ContractError;
+use crate::events::{property_id_to_string, PropertyEvents};
+use crate::storage::keys::StorageKey;
+use crate::storage::property::PropertyMetadata;
+use crate::storage::shares;
+
+/// TTL bump for per-property pause flags (~300 days)
+const PAUSED_BUMP: u32 = 2592000;
+
+/// Share token helpers
+pub struct ShareToken;
+
+impl ShareToken {
+    /// Load an active property
+    pub fn load_active(env: &Env, property_id: u64) -> PropertyMetadata {
+        let metadata = PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        if !metadata.is_active {
+            panic_with_error!(env, ContractError::PropertyNotActive);
+        }
+        metadata
+    }
+
+    /// Load a property whose shares can currently be traded
+    pub fn load_tradable(env: &Env, property_id: u64) -> PropertyMetadata {
+        let metadata = Self::load_active(env, property_id);
+        if BuyoutStorage::is_active(env, property_id) {
+            panic_with_error!(env, ContractError::TradingFrozen);
+        }
+        metadata
+    }
+
+    /// Functions paused for a property, as a mask of `PAUSE_*` flags
+    pub fn paused_functions(env: &Env, property_id: u64) -> u32 {
+        let key = StorageKey::PropertyPaused(property_id);
+        let paused = env.storage().persistent().get(&key);
+        if paused.is_some() {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PAUSED_BUMP, PAUSED_BUMP);
+        }
+        paused.unwrap_or(0)
+    }
+
+    /// Move pause flags stored by schema 2 and earlier into persistent storage
+    pub fn migrate_paused_functions(env: &Env, property_id: u64) {
+        let key = StorageKey::PropertyPaused(property_id);
+        if let Some(paused) = env.storage().instance().get::<_, u32>(&key) {
+            env.storage().instance().remove(&key);
+            env.storage().persistent().set(&key, &paused);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PAUSED_BUMP, PAUSED_BUMP);
+        }
+    }
+
+    /// Replace a property's pause flags (pausers, admin and guardians)
+    pub fn set_paused_functions(env: &Env, caller: &Address, property_id: u64, paused: u32) {
+        PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        let current = Self::paused_functions(env, property_id);
+        PauseControl::require_can_update(env, caller, current, paused);
+        let key = StorageKey::PropertyPaused(property_id);
+        env.storage().persistent().set(&key, &paused);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PAUSED_BUMP, PAUSED_BUMP);
+
+        PropertyEvents::property_pause_toggled(
+            env,
+            property_id_to_string(env, property_id),
+            paused,
+            caller.clone(),
+        );
+    }
+
+    /// Panics if the function is paused contract-wide or for the property
+    pub fn require_function_active(env: &Env, property_id: u64, flag: u32) {
+        PauseControl::require_function_active(env, flag);
+        if Self::paused_functions(env, property_id) & flag != 0 {
+            panic_with_error!(env, ContractError::FunctionPaused);
+        }
+    }
+
+    /// Convert a SEP-41 amount into a share count
+    pub fn to_shares(env: &Env, amount: i128) -> u64 {
+        if amount < 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        u64::try_from(amount)
+            .unwrap_or_else(|_| panic_with_error!(env, ContractError::MathOverflow))
+    }
+
+    /// Move shares between two holders and emit the transfer event
+    ///
+    /// Every share movement goes through here, so the property's compliance
+    /// rules are enforced for transfers, sales and collateral seizures alike.
+    /// Shares can still leave contract escrow while trading is frozen or after
+    /// the property is delisted, so holders can always get them back.
+    pub fn transfer(env: &Env, property_id: u64, from: &Address, to: &Address, amount: u64) {
+        let metadata = if *from == env.current_contract_address() {
+            PropertyMetadata::load(env, property_id)
+                .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound))
+        } else {
+            Self::load_tradable(env, property_id)
+        };
+        ComplianceControl::require_transfer_allowed(env, &metadata, from, to, amount);
+        DividendControl::settle(env, property_id, from);
+        DividendControl::settle(env, property_id, to);
+        shares::transfer_shares(env, property_id, from, to, amount);
+
+        PropertyEvents::share_transfer(
+            env,
+            property_id_to_string(env, property_id),
+            from.clone(),
+            to.clone(),
+            amount as i128,
+        );
+    }
+
+    /// Transfer shares on behalf of `from` using the spender's allowance
+    pub fn transfer_from(
+        env: &Env,
+        property_id: u64,
+        spender: &Address,
+        from: &Address,
+        to: &Address,
+        amount: u64,
+    ) {
+        shares::spend_allowance(env, property_id, from, spender, amount as i128);
+        Self::transfer(env, property_id, from, to, amount);
+    }
+
+    /// Approve a spender and emit the approval event
+    pub fn approve(
+        env: &Env,
+        property_id: u64,
+        from: &Address,
+        spender: &Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) {
+        PropertyMetadata::load(env, property_id)
+            .unwrap_or_else(|| panic_with_error!(env, ContractError::PropertyNotFound));
+        Self::to_shares(env, amount);
+        shares::set_allowance(env, property_id, from, spender, amount, expiration_ledger);
+
+        PropertyEvents::share_approval(
+            env,
+            property_id_to_string(env, property_id),
+            from.clone(),
+            spender.clone(),
+            amount,
+            expiration_ledger,
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, String, Vec};
+
+use super::legacy;
+use super::migration::{
+    MigrationStatus, MigrationStorage, CURRENT_SCHEMA_VERSION, MAX_MIGRATION_BATCH,
+};
+use crate::access::PauseControl;
+use crate::errors::ContractError;
+use crate::events::UpgradeEvents;
+use crate::lending::{PoolStorage, PositionStorage};
+use crate::registry::{RegistryControl, RegistryStorage};
+use crate::storage::config::get_property_counter;
+use crate::token::ShareToken;
+
+/// Contract upgrades and storage migrations
+///
+/// Both require the contract to be paused, and it cannot be unpaused until
+/// every property and pool has been migrated to the current schema.
+pub struct UpgradeControl;
+
+impl UpgradeControl {
+    /// Record the schema version of a fresh deployment
+    pub fn initialize(env: &Env) {
+        MigrationStorage::set_schema_version(env, CURRENT_SCHEMA_VERSION);
+    }
+
+    /// Replace the contract WASM
+    pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) {
+        PauseControl::require_paused(env);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        UpgradeEvents::contract_upgraded(
+            env,
+            new_wasm_hash.clone(),
+            MigrationStorage::get_schema_version(env),
+        );
+    }
+
+    /// Rewrite up to `max_items` properties and pools into the current schema
+    ///
+    /// Properties are migrated first, then pools. The schema version is
+    /// bumped once the last record is rewritten.
+    pub fn migrate(env: &Env, max_items: u32) -> MigrationStatus {
+        PauseControl::require_paused(env);
+        if max_items == 0 || max_items > MAX_MIGRATION_BATCH {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+
+        let mut status = MigrationStorage::get_status(env);
+        if status.is_complete() {
+            return status;
+        }
+
+        let mut remaining = max_items;
+        let last_property = get_property_counter(env);
+        while remaining > 0 && status.next_property <= last_property {
+            Self::migrate_property(env, status.next_property);
+            status.next_property += 1;
+            remaining -= 1;
+        }
+
+        let pools = PoolStorage::get_list(env);
+        while remaining > 0 && status.next_pool < pools.len() {
+            Self::migrate_pool(env, &pools.get_unchecked(status.next_pool));
+            status.next_pool += 1;
+            remaining -= 1;
+        }
+
+        if status.next_property > last_property && status.next_pool >= pools.len() {
+            status.schema_version = CURRENT_SCHEMA_VERSION;
+            MigrationStorage::set_schema_version(env, CURRENT_SCHEMA_VERSION);
+            MigrationStorage::clear_status(env);
+            UpgradeEvents::migration_completed(env, CURRENT_SCHEMA_VERSION);
+        } else {
+            MigrationStorage::set_status(env, &status);
+        }
+        status
+    }
+
+    /// Rewrite every deposit and borrow position of the given accounts
+    ///
+    /// Positions are keyed by account, so the accounts to migrate are
+    /// supplied by the caller (e.g. collected from deposit and borrow events).
+    ///
+    /// # Returns
+    /// * `u32` - Number of positions rewritten
+    pub fn migrate_positions(env: &Env, accounts: &Vec<Address>) -> u32 {
+        PauseControl::require_paused(env);
+        if accounts.len() > MAX_MIGRATION_BATCH {
+            panic_with_error!(env, ContractError::InvalidParameters);
+        }
+
+        let mut migrated = 0;
+        for account in accounts.iter() {
+            for pool_id in PositionStorage::get_user_deposits(env, &account).iter() {
+                if let Some(position) = PositionStorage::get_deposit(env, &account, &pool_id) {
+                    PositionStorage::set_deposit(env, &position);
+                    migrated += 1;
+                }
+            }
+            for pool_id in PositionStorage::get_user_borrows(env, &account).iter() {
+                if let Some(position) = legacy::read_borrow(env, &account, &pool_id) {
+                    PositionStorage::set_borrow(env, &position);
+                    migrated += 1;
+                }
+            }
+        }
+        migrated
+    }
+
+    /// Panics while stored records are older than the current schema
+    pub fn require_migrated(env: &Env) {
+        if MigrationStorage::get_schema_version(env) < CURRENT_SCHEMA_VERSION {
+            panic_with_error!(env, ContractError::MigrationPending);
+        }
+    }
+
+    // Records in the original layout are converted, and every record is
+    // saved back in the current layout with a refreshed TTL
+    fn migrate_property(env: &Env, property_id: u64) {
+        if let Some(metadata) = legacy::read_property(env, property_id) {
+            metadata.save(env);
+            if RegistryStorage::get_history(env, property_id).is_empty() {
+                RegistryControl::record_initial_valuation(env, &metadata);
+            }
+            ShareToken::migrate_paused_functions(env, property_id);
+        }
+    }
+
+    // Pools kept their original layout, so this only refreshes their TTL
+    fn migrate_pool(env: &Env, pool_id: &String) {
+        if let Some(pool) = PoolStorage::get(env, pool_id) {
+            PoolStorage::set(env, &pool);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+/// Storage key types for the upgrade module
+#[derive(Clone)]
+#[contracttype]
+pub enum UpgradeKey {
+    /// Storage schema version of the stored records
+    /// Storage: Instance
+    SchemaVersion,
+
+    /// Progress of the running migration
+    /// Storage: Instance
+    MigrationStatus,
+}
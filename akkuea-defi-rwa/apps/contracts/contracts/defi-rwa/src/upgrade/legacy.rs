@@ -0,0 +1,114 @@
+use soroban_sdk::{
+    contracttype, panic_with_error, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+};
+
+use crate::errors::ContractError;
+use crate::lending::{BorrowPosition, LendingKey};
+use crate::storage::keys::StorageKey;
+use crate::storage::property::PropertyMetadata;
+
+/// Property metadata as written by the original deployment
+///
+/// Predates property verification, so it has no `is_verified` flag. Fields
+/// match `PropertyMetadata`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyMetadataV1 {
+    pub property_id: u64,
+    pub owner: Address,
+    pub name: String,
+    pub description: String,
+    pub location: String,
+    pub valuation: i128,
+    pub total_shares: u64,
+    pub created_at: u64,
+    pub is_active: bool,
+}
+
+impl PropertyMetadataV1 {
+    /// Convert into the current layout; the property still has to be verified
+    pub fn upgrade(self) -> PropertyMetadata {
+        PropertyMetadata {
+            property_id: self.property_id,
+            owner: self.owner,
+            name: self.name,
+            description: self.description,
+            location: self.location,
+            valuation: self.valuation,
+            total_shares: self.total_shares,
+            created_at: self.created_at,
+            is_active: self.is_active,
+            is_verified: false,
+        }
+    }
+}
+
+/// Borrow position as written by the original deployment
+///
+/// Predates property share and pool receipt collateral, so the collateral is
+/// always the token at `collateral_asset`. Fields match `BorrowPosition`.
+#[contracttype]
+#[derive(Clone)]
+pub struct BorrowPositionV1 {
+    pub pool_id: String,
+    pub borrower: Address,
+    pub principal: i128,
+    pub index_at_borrow: i128,
+    pub collateral_amount: i128,
+    pub collateral_asset: Address,
+    pub borrowed_at: u64,
+}
+
+impl BorrowPositionV1 {
+    /// Convert into the current layout with token collateral
+    pub fn upgrade(self) -> BorrowPosition {
+        BorrowPosition {
+            pool_id: self.pool_id,
+            borrower: self.borrower,
+            principal: self.principal,
+            index_at_borrow: self.index_at_borrow,
+            collateral_amount: self.collateral_amount,
+            collateral_asset: self.collateral_asset,
+            collateral_property: None,
+            collateral_pool: None,
+            borrowed_at: self.borrowed_at,
+        }
+    }
+}
+
+/// Read a property in whichever layout it was stored
+///
+/// Records are told apart by the `is_verified` field, which the original
+/// layout lacks.
+pub fn read_property(env: &Env, property_id: u64) -> Option<PropertyMetadata> {
+    let raw: Map<Symbol, Val> = env
+        .storage()
+        .instance()
+        .get(&StorageKey::Property(property_id))?;
+    Some(if raw.contains_key(Symbol::new(env, "is_verified")) {
+        decode(env, raw)
+    } else {
+        decode::<PropertyMetadataV1>(env, raw).upgrade()
+    })
+}
+
+/// Read a borrow position in whichever layout it was stored
+///
+/// Records are told apart by the `collateral_pool` field, which the original
+/// layout lacks.
+pub fn read_borrow(env: &Env, borrower: &Address, pool_id: &String) -> Option<BorrowPosition> {
+    let raw: Map<Symbol, Val> = env.storage().persistent().get(&LendingKey::BorrowPosition(
+        borrower.clone(),
+        pool_id.clone(),
+    ))?;
+    Some(if raw.contains_key(Symbol::new(env, "collateral_pool")) {
+        decode(env, raw)
+    } else {
+        decode::<BorrowPositionV1>(env, raw).upgrade()
+    })
+}
+
+fn decode<T: TryFromVal<Env, Val>>(env: &Env, raw: Map<Symbol, Val>) -> T {
+    T::try_from_val(env, &raw.into_val(env))
+        .unwrap_or_else(|_| panic_with_error!(env, ContractError::MigrationPending))
+}
@@ -0,0 +1,82 @@
+use soroban_sdk::{contracttype, Env};
+
+use super::keys::UpgradeKey;
+
+/// Storage schema version this build reads and writes
+///
+/// Bump it whenever a stored record changes layout, and teach the migration
+/// how to rewrite records from the previous version.
+///
+/// * 1 - First versioned schema; properties and borrow positions written
+///   before versioning may still be in the original layouts (`legacy`)
+/// * 2 - Properties start their valuation history at registration
+/// * 3 - Property pause flags move from instance to persistent storage
+pub const CURRENT_SCHEMA_VERSION: u32 = 3;
+
+/// Largest number of records or accounts a single migration call processes
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Progress of a storage migration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationStatus {
+    /// Schema version the stored records are in
+    pub schema_version: u32,
+    /// Next property ID to rewrite
+    pub next_property: u64,
+    /// Index of the next pool in the pool list to rewrite
+    pub next_pool: u32,
+}
+
+impl MigrationStatus {
+    /// Whether every record is in the current schema
+    pub fn is_complete(&self) -> bool {
+        self.schema_version == CURRENT_SCHEMA_VERSION
+    }
+}
+
+/// Migration storage helpers
+pub struct MigrationStorage;
+
+impl MigrationStorage {
+    /// Get the stored schema version (0 for deployments that predate versioning)
+    pub fn get_schema_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&UpgradeKey::SchemaVersion)
+            .unwrap_or(0)
+    }
+
+    /// Set the stored schema version
+    pub fn set_schema_version(env: &Env, version: u32) {
+        env.storage()
+            .instance()
+            .set(&UpgradeKey::SchemaVersion, &version);
+    }
+
+    /// Get the migration progress, starting from the first record if none is running
+    pub fn get_status(env: &Env) -> MigrationStatus {
+        env.storage()
+            .instance()
+            .get(&UpgradeKey::MigrationStatus)
+            .unwrap_or(MigrationStatus {
+                schema_version: Self::get_schema_version(env),
+                next_property: 1,
+                next_pool: 0,
+            })
+    }
+
+    /// Store the progress of a running migration
+    pub fn set_status(env: &Env, status: &MigrationStatus) {
+        env.storage()
+            .instance()
+            .set(&UpgradeKey::MigrationStatus, status);
+    }
+
+    /// Drop the progress of a finished migration
+    pub fn clear_status(env: &Env) {
+        env.storage()
+            .instance()
+            .remove(&UpgradeKey::MigrationStatus);
+    }
+}
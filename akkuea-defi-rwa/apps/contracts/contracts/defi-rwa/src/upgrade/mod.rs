@@ -0,0 +1,15 @@
+// Upgrade module for DeFi RWA
+// This module replaces the contract WASM and rewrites stored records into the current
+// storage schema in bounded batches, both only while the contract is paused.
+
+mod control;
+mod keys;
+mod legacy;
+mod migration;
+
+pub use control::UpgradeControl;
+pub use keys::UpgradeKey;
+pub use legacy::{BorrowPositionV1, PropertyMetadataV1};
+pub use migration::{
+    MigrationStatus, MigrationStorage, CURRENT_SCHEMA_VERSION, MAX_MIGRATION_BATCH,
+};